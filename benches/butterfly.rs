@@ -0,0 +1,76 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ntt_iterative::{
+    ntt, FourStepPlan, Kernel, MixedRadixPlan, MontgomeryPlan, NttPlan, ShoupPlan, SimdPlan,
+    F4179340454199820289, MODULUS, PRIMITIVE_ROOT,
+};
+
+const ROUNDS: u32 = 50;
+// Transforms from `2^20` up take long enough that a few rounds suffice
+const LARGE_ROUNDS: u32 = 5;
+
+fn time(label: &str, n: usize, rounds: u32, mut f: impl FnMut(&mut [u64])) {
+    let input: Vec<u64> = (0..n as u64).map(|i| i * 7919 % MODULUS).collect();
+    let mut total = Duration::ZERO;
+    for _ in 0..rounds {
+        let mut a = input.clone();
+        let start = Instant::now();
+        f(black_box(&mut a));
+        total += start.elapsed();
+        black_box(&a);
+    }
+    println!(
+        "{label:>12} n=2^{:<2} {:>10.1?}",
+        n.trailing_zeros(),
+        total / rounds
+    );
+}
+
+fn main() {
+    for log_n in [10, 14, 18] {
+        let n = 1 << log_n;
+        let plan: NttPlan = NttPlan::new(n);
+        let radix4: NttPlan = NttPlan::new(n).with_kernel(Kernel::Radix4);
+        let montgomery: MontgomeryPlan = MontgomeryPlan::new(n);
+        let shoup: ShoupPlan = ShoupPlan::new(n);
+        let simd: SimdPlan = SimdPlan::new(n);
+        let mixed_radix: MixedRadixPlan = MixedRadixPlan::new(n);
+
+        time("ntt", n, ROUNDS, |a| ntt(a, n, PRIMITIVE_ROOT));
+        time("NttPlan", n, ROUNDS, |a| plan.forward(a));
+        time("Radix4", n, ROUNDS, |a| radix4.forward(a));
+        time("Montgomery", n, ROUNDS, |a| montgomery.forward(a));
+        time("Shoup", n, ROUNDS, |a| shoup.forward(a));
+        time("Simd", n, ROUNDS, |a| simd.forward(a));
+        time("MixedRadix", n, ROUNDS, |a| mixed_radix.forward(a));
+    }
+
+    // Above 2^32 a product no longer fits in a `u64`, and `NttPlan` reduces it with a
+    // 128-bit remainder; this is where Shoup's precomputed quotients pay off
+    for log_n in [10, 14, 18] {
+        let n = 1 << log_n;
+        let plan: NttPlan<F4179340454199820289> = NttPlan::new(n);
+        let shoup: ShoupPlan<F4179340454199820289> = ShoupPlan::new(n);
+
+        time("NttPlan p62", n, ROUNDS, |a| plan.forward(a));
+        time("Shoup p62", n, ROUNDS, |a| shoup.forward(a));
+    }
+
+    // Where `NttPlan` hands over to the four-step transform: below
+    // `FOUR_STEP_THRESHOLD` it runs radix-2 stages, from there on it matches
+    // `FourStepPlan`. `Radix2` is the radix-2 path at every size
+    for log_n in [20, 21, 22, 23] {
+        let n = 1 << log_n;
+        let plan: NttPlan = NttPlan::new(n);
+        let radix2: NttPlan = NttPlan::new(n).with_four_step(false);
+        let radix4: NttPlan = NttPlan::new(n).with_kernel(Kernel::Radix4);
+        let four_step: FourStepPlan = FourStepPlan::new(n);
+
+        time("ntt", n, LARGE_ROUNDS, |a| ntt(a, n, PRIMITIVE_ROOT));
+        time("NttPlan", n, LARGE_ROUNDS, |a| plan.forward(a));
+        time("Radix2", n, LARGE_ROUNDS, |a| radix2.forward(a));
+        time("Radix4", n, LARGE_ROUNDS, |a| radix4.forward(a));
+        time("FourStepPlan", n, LARGE_ROUNDS, |a| four_step.forward(a));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::modint::reduce_all;
+#[cfg(feature = "parallel")]
+use crate::{parallel, PARALLEL_THRESHOLD};
+use crate::{ModInt, NttError, NttField, NttPlan, F998244353};
+
+fn batch_len(batch: &[Vec<u64>]) -> Result<usize, NttError> {
+    let n = batch.first().map_or(1, Vec::len);
+    for v in batch {
+        if v.len() != n {
+            return Err(NttError::LengthMismatch {
+                expected: n,
+                actual: v.len(),
+            });
+        }
+    }
+    Ok(n)
+}
+
+fn for_each_vector(batch: &mut [Vec<u64>], f: impl Fn(&mut [u64]) + Sync) {
+    #[cfg(feature = "parallel")]
+    if batch.len() > 1 && batch.len() * batch[0].len() >= PARALLEL_THRESHOLD {
+        parallel::for_each_vector(batch, f);
+        return;
+    }
+    for v in batch.iter_mut() {
+        f(v);
+    }
+}
+
+/// Forward transform over `F` of every vector in `batch`, sharing one plan
+///
+/// All vectors must have the same power-of-two length. With the `parallel` feature,
+/// large batches are spread over the rayon thread pool one vector per task.
+pub fn ntt_batch_with<F: NttField>(batch: &mut [Vec<u64>]) -> Result<(), NttError> {
+    let plan = NttPlan::<F>::try_new(batch_len(batch)?)?;
+    for_each_vector(batch, |v| plan.forward(v));
+    Ok(())
+}
+
+/// Inverse of [`ntt_batch_with`], including the `n^-1` scaling
+pub fn intt_batch_with<F: NttField>(batch: &mut [Vec<u64>]) -> Result<(), NttError> {
+    let plan = NttPlan::<F>::try_new(batch_len(batch)?)?;
+    for_each_vector(batch, |v| plan.inverse(v));
+    Ok(())
+}
+
+/// Forward transform modulo `MODULUS` of every vector in `batch`; see [`ntt_batch_with`]
+pub fn ntt_batch(batch: &mut [Vec<u64>]) -> Result<(), NttError> {
+    ntt_batch_with::<F998244353>(batch)
+}
+
+/// Inverse transform modulo `MODULUS` of every vector in `batch`; see [`intt_batch_with`]
+pub fn intt_batch(batch: &mut [Vec<u64>]) -> Result<(), NttError> {
+    intt_batch_with::<F998244353>(batch)
+}
+
+fn check_matrix<F: NttField>(data: &[u64], rows: usize, cols: usize) -> Result<(), NttError> {
+    crate::check_size::<F>(rows)?;
+    if data.len() != rows * cols {
+        return Err(NttError::LengthMismatch {
+            expected: rows * cols,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+// Every butterfly of a column transform runs across a whole row at once, so the
+// inner loop walks contiguous memory no matter how many columns there are
+fn column_transform<F: NttField>(
+    plan: &NttPlan<F>,
+    data: &mut [u64],
+    cols: usize,
+    twiddles: &[ModInt<F>],
+) {
+    let rows = plan.n;
+    if cols == 0 {
+        return;
+    }
+    reduce_all::<F>(data);
+    for (i, &r) in plan.rev.iter().enumerate() {
+        if i < r {
+            let (head, tail) = data.split_at_mut(r * cols);
+            head[i * cols..(i + 1) * cols].swap_with_slice(&mut tail[..cols]);
+        }
+    }
+    let mut m = 1;
+    while m < rows {
+        column_stage(data, cols, m, &twiddles[m..2 * m]);
+        m <<= 1;
+    }
+}
+
+// Column transforms of a row-major `plan.len() x cols` matrix
+pub(crate) fn forward_columns<F: NttField>(plan: &NttPlan<F>, data: &mut [u64], cols: usize) {
+    column_transform(plan, data, cols, &plan.twiddles);
+}
+
+// Inverse column transforms, including the `n^-1` scaling
+pub(crate) fn inverse_columns<F: NttField>(plan: &NttPlan<F>, data: &mut [u64], cols: usize) {
+    column_transform(plan, data, cols, &plan.inv_twiddles);
+    #[cfg(feature = "parallel")]
+    if data.len() >= PARALLEL_THRESHOLD {
+        parallel::scale(data, plan.n_inv);
+        return;
+    }
+    for x in data.iter_mut() {
+        *x = (ModInt::<F>::from_reduced(*x) * plan.n_inv).value();
+    }
+}
+
+fn column_stage<F: NttField>(data: &mut [u64], cols: usize, m: usize, w: &[ModInt<F>]) {
+    #[cfg(feature = "parallel")]
+    if data.len() >= PARALLEL_THRESHOLD {
+        parallel::column_stage(data, cols, m, w);
+        return;
+    }
+    for block in data.chunks_mut(2 * m * cols) {
+        let (lo, hi) = block.split_at_mut(m * cols);
+        for ((x, y), &w) in lo.chunks_mut(cols).zip(hi.chunks_mut(cols)).zip(w) {
+            for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                let u = ModInt::<F>::from_reduced(*x);
+                let t = ModInt::<F>::from_reduced(*y) * w;
+                *x = (u + t).value();
+                *y = (u - t).value();
+            }
+        }
+    }
+}
+
+/// Forward transform over `F` of every column of the row-major `rows x cols` matrix
+/// `data`, in place
+///
+/// This is the interleaved layout of a trace matrix: element `j` of column `c` sits
+/// at `data[j * cols + c]`. `rows` must be a valid transform length for `F`.
+pub fn ntt_columns_with<F: NttField>(
+    data: &mut [u64],
+    rows: usize,
+    cols: usize,
+) -> Result<(), NttError> {
+    check_matrix::<F>(data, rows, cols)?;
+    forward_columns(&NttPlan::<F>::try_new(rows)?, data, cols);
+    Ok(())
+}
+
+/// Inverse of [`ntt_columns_with`], including the `n^-1` scaling
+pub fn intt_columns_with<F: NttField>(
+    data: &mut [u64],
+    rows: usize,
+    cols: usize,
+) -> Result<(), NttError> {
+    check_matrix::<F>(data, rows, cols)?;
+    inverse_columns(&NttPlan::<F>::try_new(rows)?, data, cols);
+    Ok(())
+}
+
+/// Column transforms modulo `MODULUS`; see [`ntt_columns_with`]
+pub fn ntt_columns(data: &mut [u64], rows: usize, cols: usize) -> Result<(), NttError> {
+    ntt_columns_with::<F998244353>(data, rows, cols)
+}
+
+/// Inverse column transforms modulo `MODULUS`; see [`intt_columns_with`]
+pub fn intt_columns(data: &mut [u64], rows: usize, cols: usize) -> Result<(), NttError> {
+    intt_columns_with::<F998244353>(data, rows, cols)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt, MODULUS, PRIMITIVE_ROOT};
+
+    fn batch(n: usize) -> Vec<Vec<u64>> {
+        (0..64u64)
+            .map(|c| (0..n as u64).map(|i| (i * i + c) % MODULUS).collect())
+            .collect()
+    }
+
+    #[test]
+    fn batch_matches_single_transforms() {
+        let n = 1 << 10;
+        let batch = batch(n);
+        let mut transformed = batch.clone();
+        assert!(ntt_batch(&mut transformed).is_ok());
+        for (v, t) in batch.iter().zip(&transformed) {
+            let mut expected = v.clone();
+            ntt(&mut expected, n, PRIMITIVE_ROOT);
+            assert_eq!(*t, expected);
+        }
+        assert!(intt_batch(&mut transformed).is_ok());
+        assert_eq!(transformed, batch);
+    }
+
+    #[test]
+    fn columns_match_batch() {
+        // The same vectors as the columns of a row-major trace matrix
+        let n = 1 << 10;
+        let batch = batch(n);
+        let cols = batch.len();
+        let mut matrix: Vec<u64> = (0..n * cols).map(|i| batch[i % cols][i / cols]).collect();
+        let original = matrix.clone();
+        assert!(ntt_columns(&mut matrix, n, cols).is_ok());
+        let mut expected = batch.clone();
+        assert!(ntt_batch(&mut expected).is_ok());
+        assert!((0..n * cols).all(|i| matrix[i] == expected[i % cols][i / cols]));
+        assert!(intt_columns(&mut matrix, n, cols).is_ok());
+        assert_eq!(matrix, original);
+    }
+
+    #[test]
+    fn rejects_bad_shapes() {
+        assert_eq!(
+            ntt_batch(&mut [vec![1, 2], vec![3]]),
+            Err(NttError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            ntt_columns(&mut [0; 6], 3, 2),
+            Err(NttError::NonPowerOfTwo(3))
+        );
+    }
+}
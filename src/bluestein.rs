@@ -0,0 +1,102 @@
+use crate::{check_size, convolve_with, ModInt, NttError, NttField, F998244353};
+
+// `t * (t - 1) / 2 mod n`, the chirp exponent
+fn triangular(t: u64, n: u64) -> u64 {
+    let t = t as u128;
+    ((t * (t.max(1) - 1) / 2) % n as u128) as u64
+}
+
+fn chirp_z<F: NttField>(a: &mut [u64], w: ModInt<F>) -> Result<(), NttError> {
+    let n = a.len();
+    let w_inv = w.inv().ok_or(NttError::NonInvertible(w.value()))?;
+
+    // `jk = T(j + k) - T(j) - T(k)`, so `X_k = w^-T(k) * sum_j (a_j w^-T(j)) w^T(j + k)`,
+    // a correlation that only needs an `n`-th root rather than a `2n`-th one
+    let pow = |base: ModInt<F>, t: usize| base.pow(triangular(t as u64, n as u64));
+    let weighted: Vec<u64> = (0..n)
+        .rev()
+        .map(|j| (ModInt::<F>::new(a[j]) * pow(w_inv, j)).value())
+        .collect();
+    let chirp: Vec<u64> = (0..2 * n - 1).map(|t| pow(w, t).value()).collect();
+
+    check_size::<F>((weighted.len() + chirp.len() - 1).next_power_of_two())?;
+    let product = convolve_with::<F>(&weighted, &chirp);
+    for (k, ak) in a.iter_mut().enumerate() {
+        *ak = (ModInt::<F>::new(product[n - 1 + k]) * pow(w_inv, k)).value();
+    }
+    Ok(())
+}
+
+/// Length-`n` DFT over `F` of `a` in place, for any `n` dividing `p - 1`
+///
+/// Uses Bluestein's chirp-z algorithm on top of the power-of-two transform, so the
+/// padded convolution length (about `3n`) must still fit the field's two-adicity.
+pub fn bluestein_ntt_with<F: NttField>(a: &mut [u64]) -> Result<(), NttError> {
+    if a.is_empty() {
+        return Ok(());
+    }
+    let w = F::root_of_unity(a.len() as u64).ok_or(NttError::NoRootOfUnity(a.len()))?;
+    chirp_z::<F>(a, ModInt::new(w))
+}
+
+/// Inverse of [`bluestein_ntt_with`]
+pub fn bluestein_intt_with<F: NttField>(a: &mut [u64]) -> Result<(), NttError> {
+    if a.is_empty() {
+        return Ok(());
+    }
+    let n = a.len();
+    let w = F::root_of_unity(n as u64).ok_or(NttError::NoRootOfUnity(n))?;
+    let w = ModInt::<F>::new(w);
+    chirp_z::<F>(a, w.inv().ok_or(NttError::NonInvertible(w.value()))?)?;
+    let n_inv = ModInt::<F>::new(n as u64)
+        .inv()
+        .ok_or(NttError::NonInvertible(n as u64))?;
+    for ai in a.iter_mut() {
+        *ai = (ModInt::<F>::new(*ai) * n_inv).value();
+    }
+    Ok(())
+}
+
+/// Arbitrary-length DFT modulo `MODULUS`; see [`bluestein_ntt_with`]
+pub fn bluestein_ntt(a: &mut [u64]) -> Result<(), NttError> {
+    bluestein_ntt_with::<F998244353>(a)
+}
+
+/// Arbitrary-length inverse DFT modulo `MODULUS`
+pub fn bluestein_intt(a: &mut [u64]) -> Result<(), NttError> {
+    bluestein_intt_with::<F998244353>(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::naive_dft;
+    use crate::{ntt, PRIMITIVE_ROOT};
+
+    #[test]
+    fn power_of_two_matches_ntt() {
+        let og: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut expected = og.clone();
+        ntt(&mut expected, 8, PRIMITIVE_ROOT);
+        let mut coefficients = og.clone();
+        assert!(bluestein_ntt(&mut coefficients).is_ok());
+        assert_eq!(coefficients, expected);
+    }
+
+    #[test]
+    fn arbitrary_lengths_match_the_definition() {
+        // 998244353 - 1 = 2^23 * 7 * 17
+        for n in [7, 14, 17, 28] {
+            let og: Vec<u64> = (1..=n).collect();
+            let mut coefficients = og.clone();
+            assert!(bluestein_ntt(&mut coefficients).is_ok());
+            assert_eq!(coefficients, naive_dft::<F998244353>(&og));
+            assert!(bluestein_intt(&mut coefficients).is_ok());
+            assert_eq!(coefficients, og);
+        }
+        assert_eq!(
+            bluestein_ntt(&mut [1, 2, 3]),
+            Err(NttError::NoRootOfUnity(3))
+        );
+    }
+}
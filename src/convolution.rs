@@ -0,0 +1,49 @@
+use crate::{ModInt, NttField, NttPlan, F998244353};
+
+/// Linear convolution of `a` and `b` over `F`
+pub fn convolve_with<F: NttField>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let plan = NttPlan::<F>::new(n);
+
+    let mut fa = a.to_vec();
+    fa.resize(n, 0);
+    let mut fb = b.to_vec();
+    fb.resize(n, 0);
+    // Pointwise products don't care about order, so both permutations are skipped
+    plan.forward_dif(&mut fa);
+    plan.forward_dif(&mut fb);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x = (ModInt::<F>::new(*x) * ModInt::new(*y)).value();
+    }
+    plan.inverse_dit(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+/// Linear convolution of `a` and `b` modulo `MODULUS`
+pub fn convolve(a: &[u64], b: &[u64]) -> Vec<u64> {
+    convolve_with::<F998244353>(a, b)
+}
+
+/// Product of two polynomials given by their coefficients, lowest degree first
+pub fn multiply_polynomials(a: &[u64], b: &[u64]) -> Vec<u64> {
+    convolve(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convolve_pads_and_truncates() {
+        let a: Vec<u64> = vec![4, 1, 4, 2, 1];
+        let b: Vec<u64> = vec![6, 1, 8];
+        let res = convolve(&a, &b);
+        assert_eq!(res, vec![24, 10, 57, 24, 40, 17, 8]);
+        assert_eq!(multiply_polynomials(&b, &a), res);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{ModInt, NttError, NttField, NttPlan, F998244353};
+
+fn scale_by_powers<F: NttField>(a: &mut [u64], base: ModInt<F>) {
+    let mut w = ModInt::<F>::one();
+    for ai in a.iter_mut() {
+        *ai = (ModInt::<F>::new(*ai) * w).value();
+        w *= base;
+    }
+}
+
+/// Evaluate the polynomial with coefficients `a` on the coset `shift * H`, in place
+///
+/// `H` is the subgroup of order `a.len()`; the coefficients are scaled by `shift^i`
+/// before the ordinary transform, so the output is in natural order.
+pub fn coset_ntt_with<F: NttField>(a: &mut [u64], shift: u64) -> Result<(), NttError> {
+    let plan = NttPlan::<F>::try_new(a.len())?;
+    if shift.is_multiple_of(F::MODULUS) {
+        return Err(NttError::NonInvertible(shift));
+    }
+    scale_by_powers(a, ModInt::<F>::new(shift));
+    plan.forward(a);
+    Ok(())
+}
+
+/// Interpolate evaluations on `shift * H` back to coefficients, in place
+pub fn coset_intt_with<F: NttField>(a: &mut [u64], shift: u64) -> Result<(), NttError> {
+    let plan = NttPlan::<F>::try_new(a.len())?;
+    let shift_inv = ModInt::<F>::new(shift)
+        .inv()
+        .ok_or(NttError::NonInvertible(shift))?;
+    plan.inverse(a);
+    scale_by_powers(a, shift_inv);
+    Ok(())
+}
+
+/// Extend evaluations on `H` to evaluations on the coset `g * H'` of a subgroup
+/// `blowup_factor` times larger, where `g` is the field generator
+///
+/// Goes evaluations to coefficients with an inverse transform, zero-pads, then runs
+/// a coset transform of the larger size.
+pub fn low_degree_extend_with<F: NttField>(
+    evals: &[u64],
+    blowup_factor: usize,
+) -> Result<Vec<u64>, NttError> {
+    let n = evals.len();
+    let mut coefficients = evals.to_vec();
+    NttPlan::<F>::try_new(n)?.inverse(&mut coefficients);
+    coefficients.resize(n * blowup_factor, 0);
+    coset_ntt_with::<F>(&mut coefficients, F::GENERATOR)?;
+    Ok(coefficients)
+}
+
+/// Coset NTT modulo `MODULUS`; see [`coset_ntt_with`]
+pub fn coset_ntt(a: &mut [u64], shift: u64) -> Result<(), NttError> {
+    coset_ntt_with::<F998244353>(a, shift)
+}
+
+/// Coset inverse NTT modulo `MODULUS`; see [`coset_intt_with`]
+pub fn coset_intt(a: &mut [u64], shift: u64) -> Result<(), NttError> {
+    coset_intt_with::<F998244353>(a, shift)
+}
+
+/// Low-degree extension modulo `MODULUS`; see [`low_degree_extend_with`]
+pub fn low_degree_extend(evals: &[u64], blowup_factor: usize) -> Result<Vec<u64>, NttError> {
+    low_degree_extend_with::<F998244353>(evals, blowup_factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt_with, Goldilocks};
+
+    // `x^3 + 3x^2 + 5` at `x`
+    fn eval(x: ModInt<Goldilocks>) -> u64 {
+        [5, 0, 3, 1]
+            .iter()
+            .rev()
+            .fold(ModInt::zero(), |acc, &c| acc * x + ModInt::new(c))
+            .value()
+    }
+
+    #[test]
+    fn coset_ntt_evaluates_on_the_coset() {
+        let shift = Goldilocks::GENERATOR;
+        let mut evals = vec![5, 0, 3, 1];
+        assert!(coset_ntt_with::<Goldilocks>(&mut evals, shift).is_ok());
+        let w = ModInt::<Goldilocks>::new(Goldilocks::root_of_unity(4).unwrap());
+        for (i, &e) in evals.iter().enumerate() {
+            assert_eq!(e, eval(ModInt::new(shift) * w.pow(i as u64)));
+        }
+        assert!(coset_intt_with::<Goldilocks>(&mut evals, shift).is_ok());
+        assert_eq!(evals, vec![5, 0, 3, 1]);
+    }
+
+    #[test]
+    fn low_degree_extension_evaluates_on_the_larger_coset() {
+        let shift = Goldilocks::GENERATOR;
+        let mut on_subgroup = vec![5, 0, 3, 1];
+        ntt_with::<Goldilocks>(&mut on_subgroup, 4, Goldilocks::GENERATOR);
+        let extended = low_degree_extend_with::<Goldilocks>(&on_subgroup, 4).unwrap();
+        let w = ModInt::<Goldilocks>::new(Goldilocks::root_of_unity(16).unwrap());
+        for (i, &e) in extended.iter().enumerate() {
+            assert_eq!(e, eval(ModInt::new(shift) * w.pow(i as u64)));
+        }
+    }
+
+    #[test]
+    fn default_field_wrappers() {
+        let mut evals = vec![1, 2, 3, 4];
+        assert!(coset_ntt(&mut evals, 3).is_ok());
+        assert!(coset_intt(&mut evals, 3).is_ok());
+        assert_eq!(evals, vec![1, 2, 3, 4]);
+        assert_eq!(low_degree_extend(&[7, 7], 8).unwrap(), vec![7; 16]);
+        assert_eq!(coset_ntt(&mut evals, 0), Err(NttError::NonInvertible(0)));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{convolve_with, ModInt, NttField, F167772161, F469762049, F998244353};
+
+type F1 = F998244353;
+type F2 = F167772161;
+type F3 = F469762049;
+
+const M1: u64 = F1::MODULUS;
+const M2: u64 = F2::MODULUS;
+const M3: u64 = F3::MODULUS;
+
+// Mixed-radix digits `(x1, x2, x3)` with `x = x1 + x2*m1 + x3*m1*m2`, via Garner's
+// algorithm; `m1_inv = m1^-1 mod m2` and `m12_inv = (m1 m2)^-1 mod m3`
+fn garner(
+    (r1, r2, r3): (u64, u64, u64),
+    m1_inv: ModInt<F2>,
+    m12_inv: ModInt<F3>,
+) -> (u64, u64, u64) {
+    let x1 = r1;
+    let x2 = ((ModInt::<F2>::new(r2) - ModInt::new(x1)) * m1_inv).value();
+    let x3 = ((ModInt::<F3>::new(r3) - ModInt::new(x1) - ModInt::new(x2) * ModInt::new(M1))
+        * m12_inv)
+        .value();
+    (x1, x2, x3)
+}
+
+fn convolve_residues(a: &[u64], b: &[u64]) -> impl Iterator<Item = (u64, u64, u64)> {
+    let c1 = convolve_with::<F1>(a, b);
+    let c2 = convolve_with::<F2>(a, b);
+    let c3 = convolve_with::<F3>(a, b);
+    // Computed once here rather than per coefficient, each is a full exponentiation
+    let m1_inv = ModInt::<F2>::new(M1).inv().expect("moduli are coprime");
+    let m12_inv = (ModInt::<F3>::new(M1) * ModInt::new(M2))
+        .inv()
+        .expect("moduli are coprime");
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(move |((r1, r2), r3)| garner((r1, r2, r3), m1_inv, m12_inv))
+}
+
+/// Linear convolution of `a` and `b` modulo an arbitrary `modulus`
+///
+/// Runs the transform over three NTT primes and recombines with CRT, which is exact
+/// as long as every coefficient of the integer convolution is below
+/// `998244353 * 167772161 * 469762049` (about `2^86`). With inputs below `modulus`
+/// that holds for `modulus < 2^31` and lengths up to `2^23`.
+///
+/// Panics if `min(a.len(), b.len()) * (modulus - 1)^2` reaches that product, since the
+/// result could then be wrong.
+pub fn convolve_mod(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    let m = modulus as u128;
+    let bound = (m - 1)
+        .checked_mul(m - 1)
+        .and_then(|sq| sq.checked_mul(a.len().min(b.len()) as u128));
+    assert!(
+        bound.is_some_and(|bound| bound < M1 as u128 * M2 as u128 * M3 as u128),
+        "convolve_mod: coefficients can reach {} * ({modulus} - 1)^2, \
+         beyond the exact range of three-prime CRT (about 2^86)",
+        a.len().min(b.len()),
+    );
+    let a: Vec<u64> = a.iter().map(|&x| x % modulus).collect();
+    let b: Vec<u64> = b.iter().map(|&x| x % modulus).collect();
+    let m1 = M1 as u128 % m;
+    let m12 = (M1 as u128 * M2 as u128) % m;
+    convolve_residues(&a, &b)
+        .map(|(x1, x2, x3)| ((x1 as u128 + x2 as u128 * m1 + x3 as u128 * m12) % m) as u64)
+        .collect()
+}
+
+/// Exact integer linear convolution of `a` and `b`
+///
+/// Each coefficient of the result must be below `998244353 * 167772161 * 469762049`
+/// (about `2^86`), otherwise it is returned reduced modulo that product.
+pub fn convolve_exact_u128(a: &[u64], b: &[u64]) -> Vec<u128> {
+    let m1 = M1 as u128;
+    let m12 = m1 * M2 as u128;
+    convolve_residues(a, b)
+        .map(|(x1, x2, x3)| x1 as u128 + x2 as u128 * m1 + x3 as u128 * m12)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convolve_mod_matches_schoolbook() {
+        let m = 1_000_000_007;
+        let a: Vec<u64> = vec![m - 1, m - 2, 123_456_789, 987_654_321];
+        let b: Vec<u64> = vec![m - 3, 555_555_555, 1];
+        let mut expected = vec![0; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] = (expected[i + j] + x as u128 * y as u128 % m as u128) % m as u128;
+            }
+        }
+        let expected: Vec<u64> = expected.into_iter().map(|x| x as u64).collect();
+        assert_eq!(convolve_mod(&a, &b, m), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond the exact range")]
+    fn convolve_mod_rejects_coefficients_beyond_crt_range() {
+        let m = (1 << 61) - 1;
+        convolve_mod(&[m - 1, m - 2], &[m - 3, m - 4], m);
+    }
+
+    #[test]
+    fn convolve_exact_u128_is_exact() {
+        let m = 1_000_000_007;
+        let exact = convolve_exact_u128(&[m - 1, m - 1], &[m - 1, m - 1]);
+        let sq = (m - 1) as u128 * (m - 1) as u128;
+        assert_eq!(exact, vec![sq, 2 * sq, sq]);
+    }
+}
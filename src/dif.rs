@@ -0,0 +1,109 @@
+use crate::modint::reduce_all;
+use crate::{ModInt, NttField, F998244353};
+
+/// Forward NTT over `F` by decimation in frequency, natural order in and bit-reversed order out
+pub fn ntt_dif_no_bitrev_with<F: NttField>(a: &mut [u64], n: usize, primitive_root: u64) {
+    reduce_all::<F>(&mut a[..n]);
+    let mut mh = n;
+    while mh > 1 {
+        let m = mh >> 1;
+        let base = ModInt::<F>::new(primitive_root).pow((F::MODULUS - 1) / mh as u64);
+        let mut w = ModInt::<F>::one();
+        for j in 0..m {
+            for k in (0..n).step_by(mh) {
+                let u = ModInt::<F>::from_reduced(a[k + j]);
+                let v = ModInt::<F>::from_reduced(a[k + j + m]);
+                a[k + j] = (u + v).value();
+                a[k + j + m] = ((u - v) * w).value();
+            }
+            w *= base;
+        }
+        mh = m;
+    }
+}
+
+/// Inverse NTT over `F` by decimation in time, bit-reversed order in and natural order out
+///
+/// Undoes [`ntt_dif_no_bitrev_with`] given the same `primitive_root`.
+pub fn intt_dit_no_bitrev_with<F: NttField>(a: &mut [u64], n: usize, primitive_root: u64) {
+    let root_inv = ModInt::<F>::new(primitive_root).pow(F::MODULUS - 2);
+    reduce_all::<F>(&mut a[..n]);
+    let mut mh = 2;
+    while mh <= n {
+        let m = mh >> 1;
+        let base = root_inv.pow((F::MODULUS - 1) / mh as u64);
+        let mut w = ModInt::<F>::one();
+        for j in 0..m {
+            for k in (0..n).step_by(mh) {
+                let u = ModInt::<F>::from_reduced(a[k + j]);
+                let t = ModInt::<F>::from_reduced(a[k + j + m]) * w;
+                a[k + j] = (u + t).value();
+                a[k + j + m] = (u - t).value();
+            }
+            w *= base;
+        }
+        mh <<= 1;
+    }
+    let n_inv = ModInt::<F>::new(n as u64).pow(F::MODULUS - 2);
+    for ai in a[..n].iter_mut() {
+        *ai = (ModInt::<F>::from_reduced(*ai) * n_inv).value();
+    }
+}
+
+/// Forward NTT with bit-reversed output; see [`ntt_dif_no_bitrev_with`]
+pub fn ntt_dif_no_bitrev(a: &mut [u64], n: usize, primitive_root: u64) {
+    ntt_dif_no_bitrev_with::<F998244353>(a, n, primitive_root);
+}
+
+/// Inverse NTT of bit-reversed input; see [`intt_dit_no_bitrev_with`]
+pub fn intt_dit_no_bitrev(a: &mut [u64], n: usize, primitive_root: u64) {
+    intt_dit_no_bitrev_with::<F998244353>(a, n, primitive_root);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bit_reverse_permute, ntt, NttPlan, MODULUS, PRIMITIVE_ROOT};
+
+    #[test]
+    fn dif_dit_convolve_without_bit_reversal() {
+        let n = 8;
+        let mut vec0: Vec<u64> = vec![4, 1, 4, 2, 1, 3, 5, 6];
+        let mut vec1: Vec<u64> = vec![6, 1, 8, 0, 3, 3, 9, 8];
+
+        let mut expected = vec0.clone();
+        ntt(&mut expected, n, PRIMITIVE_ROOT);
+        ntt_dif_no_bitrev(&mut vec0, n, PRIMITIVE_ROOT);
+        ntt_dif_no_bitrev(&mut vec1, n, PRIMITIVE_ROOT);
+        let mut natural = vec0.clone();
+        bit_reverse_permute(&mut natural);
+        assert_eq!(natural, expected);
+
+        let mut res: Vec<u64> = vec0
+            .iter()
+            .zip(&vec1)
+            .map(|(&x, &y)| (ModInt::<F998244353>::new(x) * ModInt::new(y)).value())
+            .collect();
+        intt_dit_no_bitrev(&mut res, n, PRIMITIVE_ROOT);
+        assert_eq!(res, vec![123, 120, 106, 92, 139, 144, 140, 124]);
+
+        let plan: NttPlan = NttPlan::new(n);
+        let mut coefficients: Vec<u64> = vec![4, 1, 4, 2, 1, 3, 5, 6];
+        plan.forward_dif(&mut coefficients);
+        assert_eq!(coefficients, vec0);
+        plan.inverse_dit(&mut coefficients);
+        assert_eq!(coefficients, vec![4, 1, 4, 2, 1, 3, 5, 6]);
+    }
+
+    #[test]
+    fn unreduced_inputs_transform_like_their_residues() {
+        let n = 16;
+        let reduced: Vec<u64> = (0..n as u64).map(|i| i * 12345 % MODULUS).collect();
+        let mut expected = reduced.clone();
+        ntt(&mut expected, n, PRIMITIVE_ROOT);
+        let mut a: Vec<u64> = reduced.iter().map(|&x| x + 3 * MODULUS).collect();
+        ntt_dif_no_bitrev(&mut a, n, PRIMITIVE_ROOT);
+        bit_reverse_permute(&mut a);
+        assert_eq!(a, expected);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::plan::bit_reverse;
+use crate::{power_mod, Montgomery, NttField};
+
+/// The ML-DSA (Dilithium) field, `q = 8380417 = 1023 * 2^13 + 1`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dilithium;
+
+impl NttField for Dilithium {
+    const MODULUS: u64 = 8_380_417;
+    const GENERATOR: u64 = 10;
+    const TWO_ADICITY: u32 = 13;
+}
+
+/// ML-DSA modulus `q`
+pub const DILITHIUM_Q: u64 = Dilithium::MODULUS;
+/// ML-DSA polynomial length `n`
+pub const DILITHIUM_N: usize = 256;
+
+// Primitive 512th root of unity used by FIPS 204
+const ZETA: u64 = 1753;
+// `256^-1 mod q`
+const N_INV: u64 = 8_347_681;
+
+// `x * 2^64 mod q`, so that a Montgomery product with a plain value stays plain
+const fn to_montgomery(x: u64) -> u64 {
+    (((x as u128) << 64) % DILITHIUM_Q as u128) as u64
+}
+
+/// `zeta^BitRev8(i) mod q`, the twiddle order of FIPS 204 Appendix B
+pub const DILITHIUM_ZETAS: [u64; 256] = {
+    let mut zetas = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        zetas[i] = power_mod(ZETA, bit_reverse(i as u64, 8), DILITHIUM_Q);
+        i += 1;
+    }
+    zetas
+};
+
+const ZETAS_MONTGOMERY: [u64; 256] = {
+    let mut zetas = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        zetas[i] = to_montgomery(DILITHIUM_ZETAS[i]);
+        i += 1;
+    }
+    zetas
+};
+
+type Mont = Montgomery<Dilithium>;
+
+/// Negacyclic NTT of FIPS 204 (Algorithm 41), in place, with Montgomery reduction
+pub fn dilithium_ntt(w: &mut [u64; DILITHIUM_N]) {
+    let mut m = 0;
+    let mut len = 128;
+    while len >= 1 {
+        for start in (0..DILITHIUM_N).step_by(2 * len) {
+            m += 1;
+            let z = ZETAS_MONTGOMERY[m];
+            for j in start..start + len {
+                let t = Mont::mul(z, w[j + len]);
+                w[j + len] = Mont::sub(w[j], t);
+                w[j] = Mont::add(w[j], t);
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// Inverse of [`dilithium_ntt`] (FIPS 204 Algorithm 42), in place
+pub fn dilithium_intt(w: &mut [u64; DILITHIUM_N]) {
+    let mut m = 256;
+    let mut len = 1;
+    while len < DILITHIUM_N {
+        for start in (0..DILITHIUM_N).step_by(2 * len) {
+            m -= 1;
+            let z = Mont::sub(0, ZETAS_MONTGOMERY[m]);
+            for j in start..start + len {
+                let t = w[j];
+                w[j] = Mont::add(t, w[j + len]);
+                w[j + len] = Mont::mul(z, Mont::sub(t, w[j + len]));
+            }
+        }
+        len <<= 1;
+    }
+    let f = to_montgomery(N_INV);
+    for x in w.iter_mut() {
+        *x = Mont::mul(f, *x);
+    }
+}
+
+/// Pointwise product of two [`dilithium_ntt`] outputs
+pub fn dilithium_multiply_ntts(
+    a: &[u64; DILITHIUM_N],
+    b: &[u64; DILITHIUM_N],
+) -> [u64; DILITHIUM_N] {
+    let mut c = [0; DILITHIUM_N];
+    for ((ci, &x), &y) in c.iter_mut().zip(a).zip(b) {
+        *ci = Mont::mul(Mont::to_montgomery(x), y);
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::negacyclic_schoolbook;
+
+    #[test]
+    fn zetas_match_fips_204() {
+        // FIPS 204, Appendix B
+        assert_eq!(
+            DILITHIUM_ZETAS[1..10],
+            [4808194, 3765607, 3761513, 5178923, 5496691, 5234739, 5178987, 7778734, 3542485]
+        );
+        assert_eq!(DILITHIUM_ZETAS[252..], [1900052, 7598542, 1054478, 7648983]);
+    }
+
+    #[test]
+    fn constant_evaluates_to_itself() {
+        let mut one = [0; DILITHIUM_N];
+        one[0] = 1;
+        dilithium_ntt(&mut one);
+        assert!(one.iter().all(|&x| x == 1));
+    }
+
+    #[test]
+    fn multiply_ntts_is_negacyclic_product() {
+        let mut a = [0; DILITHIUM_N];
+        let mut b = [0; DILITHIUM_N];
+        for i in 0..DILITHIUM_N {
+            a[i] = (i as u64 * 7919 + 5) % DILITHIUM_Q;
+            b[i] = DILITHIUM_Q - 1 - i as u64 * i as u64;
+        }
+        let expected = negacyclic_schoolbook::<Dilithium>(&a, &b);
+        let og = a;
+        dilithium_ntt(&mut a);
+        dilithium_ntt(&mut b);
+        let mut c = dilithium_multiply_ntts(&a, &b);
+        dilithium_intt(&mut c);
+        assert_eq!(c.to_vec(), expected);
+        dilithium_intt(&mut a);
+        assert_eq!(a, og);
+    }
+}
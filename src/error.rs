@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt;
+
+/// Reasons a transform cannot be performed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NttError {
+    /// The transform length is not a power of two
+    NonPowerOfTwo(usize),
+    /// The transform length exceeds the largest power-of-two order in the field
+    SizeExceedsMaxOrder { size: usize, max: u64 },
+    /// The slice length does not match the transform length
+    LengthMismatch { expected: usize, actual: usize },
+    /// The element has no inverse modulo the field prime
+    NonInvertible(u64),
+    /// The field has no primitive root of unity of this order
+    NoRootOfUnity(usize),
+    /// The field prime is too large for the requested backend
+    UnsupportedModulus(u64),
+}
+
+impl fmt::Display for NttError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NttError::NonPowerOfTwo(n) => write!(f, "transform length {n} is not a power of two"),
+            NttError::SizeExceedsMaxOrder { size, max } => {
+                write!(f, "transform length {size} exceeds the maximum order {max}")
+            }
+            NttError::LengthMismatch { expected, actual } => {
+                write!(f, "expected a slice of length {expected}, got {actual}")
+            }
+            NttError::NonInvertible(x) => write!(f, "{x} is not invertible"),
+            NttError::NoRootOfUnity(n) => write!(f, "no primitive {n}-th root of unity"),
+            NttError::UnsupportedModulus(p) => {
+                write!(f, "modulus {p} is not supported by this backend")
+            }
+        }
+    }
+}
+
+impl Error for NttError {}
@@ -0,0 +1,155 @@
+use crate::power_mod;
+
+/// An NTT-friendly prime field `Z/pZ`
+pub trait NttField {
+    /// The prime `p`
+    const MODULUS: u64;
+    /// A generator of the multiplicative group of order `p - 1`
+    const GENERATOR: u64;
+    /// The largest `k` such that `2^k` divides `p - 1`
+    const TWO_ADICITY: u32;
+
+    /// Largest power-of-two transform size supported by the field
+    fn max_ntt_size() -> u64 {
+        1 << Self::TWO_ADICITY
+    }
+
+    /// Reduce a double-width product modulo `p`
+    #[inline]
+    fn reduce_wide(x: u128) -> u64 {
+        // Products of two residues of a prime below 2^32 fit in a u64, and a 64-bit
+        // `%` is much cheaper than a 128-bit one
+        if Self::MODULUS < 1 << 32 && x <= u64::MAX as u128 {
+            return x as u64 % Self::MODULUS;
+        }
+        (x % Self::MODULUS as u128) as u64
+    }
+
+    /// Primitive `n`-th root of unity, if `n` divides `p - 1`
+    fn root_of_unity(n: u64) -> Option<u64> {
+        if n == 0 || (Self::MODULUS - 1) % n != 0 {
+            return None;
+        }
+        Some(power_mod(
+            Self::GENERATOR,
+            (Self::MODULUS - 1) / n,
+            Self::MODULUS,
+        ))
+    }
+}
+
+macro_rules! ntt_field {
+    ($(#[$doc:meta])* $name:ident, $modulus:expr, $generator:expr, $two_adicity:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl NttField for $name {
+            const MODULUS: u64 = $modulus;
+            const GENERATOR: u64 = $generator;
+            const TWO_ADICITY: u32 = $two_adicity;
+        }
+    };
+}
+
+ntt_field!(
+    /// `998244353 = 119 * 2^23 + 1`
+    F998244353, 998_244_353, 3, 23
+);
+ntt_field!(
+    /// `469762049 = 7 * 2^26 + 1`
+    F469762049, 469_762_049, 3, 26
+);
+ntt_field!(
+    /// `167772161 = 5 * 2^25 + 1`
+    F167772161, 167_772_161, 3, 25
+);
+ntt_field!(
+    /// `754974721 = 45 * 2^24 + 1`
+    F754974721, 754_974_721, 11, 24
+);
+ntt_field!(
+    /// `4179340454199820289 = 29 * 2^57 + 1`, a 62-bit prime
+    F4179340454199820289, 4_179_340_454_199_820_289, 3, 57
+);
+
+/// Goldilocks prime `2^64 - 2^32 + 1`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Goldilocks;
+
+impl Goldilocks {
+    // `2^64 mod p`
+    const EPSILON: u64 = 0xffff_ffff;
+}
+
+impl NttField for Goldilocks {
+    const MODULUS: u64 = 0xffff_ffff_0000_0001;
+    const GENERATOR: u64 = 7;
+    const TWO_ADICITY: u32 = 32;
+
+    // Uses `2^64 = 2^32 - 1` and `2^96 = -1 (mod p)` instead of a 128-bit division
+    #[inline]
+    fn reduce_wide(x: u128) -> u64 {
+        let lo = x as u64;
+        let hi = (x >> 64) as u64;
+        let hi_hi = hi >> 32;
+        let hi_lo = hi & Self::EPSILON;
+
+        let (mut t0, borrow) = lo.overflowing_sub(hi_hi);
+        if borrow {
+            t0 -= Self::EPSILON;
+        }
+        let t1 = hi_lo * Self::EPSILON;
+        let (t2, carry) = t0.overflowing_add(t1);
+        let t2 = t2.wrapping_add(Self::EPSILON * carry as u64);
+        if t2 >= Self::MODULUS {
+            t2 - Self::MODULUS
+        } else {
+            t2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{intt_with, mul_mod, ntt_with, try_intt_with, try_ntt_with, ModInt};
+
+    fn roundtrip<F: NttField>() {
+        let og: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut coefficients = og.clone();
+        ntt_with::<F>(&mut coefficients, 8, F::GENERATOR);
+        intt_with::<F>(&mut coefficients, 8, F::GENERATOR);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn every_field_roundtrips() {
+        roundtrip::<F998244353>();
+        roundtrip::<F469762049>();
+        roundtrip::<F167772161>();
+        roundtrip::<F754974721>();
+        roundtrip::<Goldilocks>();
+        roundtrip::<F4179340454199820289>();
+    }
+
+    #[test]
+    fn goldilocks_reduction_matches_u128() {
+        let p = Goldilocks::MODULUS;
+        for (a, b) in [
+            (p - 1, p - 1),
+            (p - 2, 3),
+            (1 << 63, 1 << 40),
+            (0xdead_beef, p - 7),
+        ] {
+            let product = ModInt::<Goldilocks>::new(a) * ModInt::new(b);
+            assert_eq!(product.value(), mul_mod(a, b, p));
+        }
+        let mut coefficients: Vec<u64> = vec![p - 1, p - 2, 1 << 63, 0xdead_beef];
+        let og = coefficients.clone();
+        assert!(try_ntt_with::<Goldilocks>(&mut coefficients, 4, Goldilocks::GENERATOR).is_ok());
+        assert!(try_intt_with::<Goldilocks>(&mut coefficients, 4, Goldilocks::GENERATOR).is_ok());
+        assert_eq!(coefficients, og);
+        assert_eq!(Goldilocks::max_ntt_size(), 1 << 32);
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::sync::Mutex;
+
+use crate::batch::{forward_columns, inverse_columns};
+#[cfg(feature = "parallel")]
+use crate::{parallel, PARALLEL_THRESHOLD};
+use crate::{Kernel, ModInt, NttError, NttField, NttPlan, F998244353};
+
+/// Transforms of at least this length are run by [`NttPlan`] with the four-step algorithm
+///
+/// Below it the radix-2 path is as fast or faster; `benches/butterfly.rs` compares both
+/// from `2^20` to `2^23`.
+pub const FOUR_STEP_THRESHOLD: usize = 1 << 21;
+
+/// Bailey's four-step transform for sizes that don't fit in cache
+///
+/// The length-`n` input is viewed as a row-major `n2 x n1` matrix. `n1` transforms of
+/// length `n2` run down its columns, with every butterfly working on whole rows; the
+/// results are scaled by `w_n^(j1 k2)`, and `n2` transforms of length `n1` run on the
+/// contiguous rows. One transpose then puts the output in natural order.
+#[derive(Clone, Debug)]
+pub struct FourStepPlan<F: NttField = F998244353> {
+    n: usize,
+    n1: usize,
+    // Length `n2`, run down the columns
+    cols: NttPlan<F>,
+    // Length `n1`, run on the rows
+    rows: NttPlan<F>,
+    root: ModInt<F>,
+    inv_root: ModInt<F>,
+    scratch: Scratch,
+}
+
+// Transpose buffer owned by the plan, so that repeated transforms don't allocate; a
+// transform that finds it taken (one plan shared by several threads) uses a
+// temporary buffer instead
+#[derive(Default)]
+struct Scratch(Mutex<Vec<u64>>);
+
+impl fmt::Debug for Scratch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Scratch")
+    }
+}
+
+// A clone gets its own buffer, grown on its first transform
+impl Clone for Scratch {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<F: NttField> FourStepPlan<F> {
+    /// Build a plan for transforms of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, validating the size
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        crate::check_size::<F>(n)?;
+        let n1 = 1 << (n.trailing_zeros() / 2);
+        let n2 = n / n1;
+        let root = ModInt::<F>::new(F::root_of_unity(n as u64).expect("size was checked"));
+        Ok(Self {
+            n,
+            n1,
+            cols: NttPlan::try_new(n2)?,
+            rows: NttPlan::try_new(n1)?,
+            root,
+            inv_root: root.inv().expect("roots of unity are invertible"),
+            scratch: Scratch(Mutex::new(vec![0; n])),
+        })
+    }
+
+    /// Select the butterfly kernel of the row transforms
+    ///
+    /// The column transforms always run radix-2 stages over whole rows.
+    pub fn with_kernel(self, kernel: Kernel) -> Self {
+        Self {
+            rows: self.rows.with_kernel(kernel),
+            ..self
+        }
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Forward transform of `a` in place
+    pub fn forward(&self, a: &mut [u64]) {
+        self.transform(a, false);
+    }
+
+    /// Inverse transform of `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        self.transform(a, true);
+    }
+
+    fn transform(&self, a: &mut [u64], inverse: bool) {
+        let n1 = self.n1;
+        let a = &mut a[..self.n];
+
+        // `x[j1 + n1 j2]` sits at row `j2`, column `j1`. The sub-plans scale by `n2^-1`
+        // and `n1^-1` on the way back, which multiply to `n^-1`
+        let root = if inverse {
+            inverse_columns(&self.cols, a, n1);
+            self.inv_root
+        } else {
+            forward_columns(&self.cols, a, n1);
+            self.root
+        };
+        for_each_row(a, n1, |k2, row| {
+            let step = root.pow(k2 as u64);
+            let mut w = ModInt::<F>::one();
+            for x in row.iter_mut() {
+                *x = (ModInt::<F>::from_reduced(*x) * w).value();
+                w *= step;
+            }
+            if inverse {
+                self.rows.inverse(row);
+            } else {
+                self.rows.forward(row);
+            }
+        });
+
+        // Entry `(k2, k1)` is `X[k2 + n2 k1]`
+        let mut guard = self.scratch.0.try_lock().ok();
+        let mut temp = Vec::new();
+        let scratch = guard.as_deref_mut().unwrap_or(&mut temp);
+        scratch.resize(self.n, 0);
+        transpose(a, scratch, self.n / n1, n1);
+        a.copy_from_slice(scratch);
+    }
+}
+
+// Run `f(i, row)` on every `len`-element row `i` of `data`
+fn for_each_row(data: &mut [u64], len: usize, f: impl Fn(usize, &mut [u64]) + Sync) {
+    #[cfg(feature = "parallel")]
+    if data.len() >= PARALLEL_THRESHOLD {
+        parallel::for_each_row(data, len, f);
+        return;
+    }
+    for (i, row) in data.chunks_mut(len).enumerate() {
+        f(i, row);
+    }
+}
+
+// Side of the square tiles `transpose` moves at once
+const BLOCK: usize = 32;
+
+// Out-of-place transpose of a row-major `rows x cols` matrix, one band of `BLOCK`
+// destination rows at a time; each `BLOCK x BLOCK` tile goes through a local buffer
+// so that reads and writes are both contiguous runs, whatever the power-of-two strides
+fn transpose(src: &[u64], dst: &mut [u64], rows: usize, cols: usize) {
+    for_each_row(dst, BLOCK * rows, |b, band| {
+        let c0 = b * BLOCK;
+        let band_cols = band.len() / rows;
+        let mut tile = [[0; BLOCK]; BLOCK];
+        for r0 in (0..rows).step_by(BLOCK) {
+            let tile_rows = BLOCK.min(rows - r0);
+            for (dr, tile_row) in tile[..tile_rows].iter_mut().enumerate() {
+                let start = (r0 + dr) * cols + c0;
+                tile_row[..band_cols].copy_from_slice(&src[start..start + band_cols]);
+            }
+            for c in 0..band_cols {
+                let out = &mut band[c * rows + r0..c * rows + r0 + tile_rows];
+                for (dr, x) in out.iter_mut().enumerate() {
+                    *x = tile[dr][c];
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MODULUS;
+
+    #[test]
+    fn four_step_matches_plan() {
+        for log_n in 0..=11 {
+            let n = 1 << log_n;
+            let og: Vec<u64> = (0..n as u64).map(|i| i * i % MODULUS).collect();
+            let plan: NttPlan = NttPlan::new(n);
+            let four_step: FourStepPlan = FourStepPlan::new(n);
+            let mut expected = og.clone();
+            let mut coefficients = og.clone();
+            plan.forward(&mut expected);
+            four_step.forward(&mut coefficients);
+            assert_eq!(coefficients, expected);
+            four_step.inverse(&mut coefficients);
+            assert_eq!(coefficients, og);
+        }
+    }
+
+    #[test]
+    fn plan_at_threshold_matches_radix2() {
+        // `NttPlan` switches to four-step at the threshold; both kernels must still
+        // match the radix-2 path
+        let n = FOUR_STEP_THRESHOLD;
+        let og: Vec<u64> = (0..n as u64).map(|i| i * 7919 % MODULUS).collect();
+        let mut expected = og.clone();
+        NttPlan::<F998244353>::new(n)
+            .with_four_step(false)
+            .forward(&mut expected);
+        for kernel in [Kernel::Radix2, Kernel::Radix4] {
+            let plan: NttPlan = NttPlan::new(n).with_kernel(kernel);
+            let mut coefficients = og.clone();
+            plan.forward(&mut coefficients);
+            assert_eq!(coefficients, expected);
+            plan.inverse(&mut coefficients);
+            assert_eq!(coefficients, og);
+        }
+    }
+}
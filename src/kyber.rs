@@ -0,0 +1,162 @@
+use crate::plan::bit_reverse;
+use crate::{power_mod, ModInt, NttField};
+
+/// The ML-KEM (Kyber) field, `q = 3329 = 13 * 2^8 + 1`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Kyber;
+
+impl NttField for Kyber {
+    const MODULUS: u64 = 3329;
+    const GENERATOR: u64 = 3;
+    const TWO_ADICITY: u32 = 8;
+}
+
+/// ML-KEM modulus `q`
+pub const KYBER_Q: u64 = Kyber::MODULUS;
+/// ML-KEM polynomial length `n`
+pub const KYBER_N: usize = 256;
+
+// Primitive 256th root of unity used by FIPS 203
+const ZETA: u64 = 17;
+// `128^-1 mod q`
+const N_INV: u64 = 3303;
+
+/// `zeta^BitRev7(i) mod q`, the twiddle order of FIPS 203 Appendix A
+pub const KYBER_ZETAS: [u64; 128] = {
+    let mut zetas = [0; 128];
+    let mut i = 0;
+    while i < 128 {
+        zetas[i] = power_mod(ZETA, bit_reverse(i as u64, 7), KYBER_Q);
+        i += 1;
+    }
+    zetas
+};
+
+/// `zeta^(2 BitRev7(i) + 1) mod q`, the moduli `X^2 - gamma_i` used by `kyber_basemul`
+pub const KYBER_GAMMAS: [u64; 128] = {
+    let mut gammas = [0; 128];
+    let mut i = 0;
+    while i < 128 {
+        gammas[i] = power_mod(ZETA, 2 * bit_reverse(i as u64, 7) + 1, KYBER_Q);
+        i += 1;
+    }
+    gammas
+};
+
+type Zq = ModInt<Kyber>;
+
+/// Incomplete 7-layer NTT of FIPS 203 (Algorithm 9), in place
+///
+/// The output holds 128 degree-1 residues `f mod (X^2 - gamma_i)` as coefficient pairs.
+pub fn kyber_ntt(f: &mut [u64; KYBER_N]) {
+    let mut i = 1;
+    let mut len = 128;
+    while len >= 2 {
+        for start in (0..KYBER_N).step_by(2 * len) {
+            let zeta = Zq::new(KYBER_ZETAS[i]);
+            i += 1;
+            for j in start..start + len {
+                let t = zeta * Zq::new(f[j + len]);
+                let u = Zq::new(f[j]);
+                f[j + len] = (u - t).value();
+                f[j] = (u + t).value();
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// Inverse of [`kyber_ntt`] (FIPS 203 Algorithm 10), in place
+pub fn kyber_intt(f: &mut [u64; KYBER_N]) {
+    let mut i = 127;
+    let mut len = 2;
+    while len <= 128 {
+        for start in (0..KYBER_N).step_by(2 * len) {
+            let zeta = Zq::new(KYBER_ZETAS[i]);
+            i -= 1;
+            for j in start..start + len {
+                let t = Zq::new(f[j]);
+                let u = Zq::new(f[j + len]);
+                f[j] = (t + u).value();
+                f[j + len] = (zeta * (u - t)).value();
+            }
+        }
+        len <<= 1;
+    }
+    for x in f.iter_mut() {
+        *x = (Zq::new(*x) * Zq::new(N_INV)).value();
+    }
+}
+
+/// Product of `a0 + a1 X` and `b0 + b1 X` modulo `X^2 - gamma` (FIPS 203 Algorithm 12)
+pub fn kyber_basemul(a: (u64, u64), b: (u64, u64), gamma: u64) -> (u64, u64) {
+    let (a0, a1, b0, b1) = (Zq::new(a.0), Zq::new(a.1), Zq::new(b.0), Zq::new(b.1));
+    let c0 = a0 * b0 + a1 * b1 * Zq::new(gamma);
+    let c1 = a0 * b1 + a1 * b0;
+    (c0.value(), c1.value())
+}
+
+/// Pointwise product of two [`kyber_ntt`] outputs (FIPS 203 Algorithm 11)
+pub fn kyber_multiply_ntts(f: &[u64; KYBER_N], g: &[u64; KYBER_N]) -> [u64; KYBER_N] {
+    let mut h = [0; KYBER_N];
+    for (i, &gamma) in KYBER_GAMMAS.iter().enumerate() {
+        (h[2 * i], h[2 * i + 1]) =
+            kyber_basemul((f[2 * i], f[2 * i + 1]), (g[2 * i], g[2 * i + 1]), gamma);
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::negacyclic_schoolbook;
+
+    #[test]
+    fn zetas_match_fips_203() {
+        // FIPS 203, Appendix A
+        assert_eq!(
+            KYBER_ZETAS[..16],
+            [
+                1, 1729, 2580, 3289, 2642, 630, 1897, 848, 1062, 1919, 193, 797, 2786, 3260, 569,
+                1746
+            ]
+        );
+        assert_eq!(
+            KYBER_ZETAS[120..],
+            [1722, 1212, 1874, 1029, 2110, 2935, 885, 2154]
+        );
+        assert_eq!(KYBER_GAMMAS[..4], [17, KYBER_Q - 17, 2761, KYBER_Q - 2761]);
+    }
+
+    #[test]
+    fn ntt_reduces_modulo_each_quadratic() {
+        // X maps to (0, 1) and X^2 to (gamma_i, 0) in every residue ring
+        let mut x = [0; KYBER_N];
+        x[1] = 1;
+        kyber_ntt(&mut x);
+        assert!(x.chunks(2).all(|pair| pair == [0, 1]));
+        let mut x2 = [0; KYBER_N];
+        x2[2] = 1;
+        kyber_ntt(&mut x2);
+        assert!((0..128).all(|i| x2[2 * i] == KYBER_GAMMAS[i] && x2[2 * i + 1] == 0));
+    }
+
+    #[test]
+    fn multiply_ntts_is_negacyclic_product() {
+        let mut a = [0; KYBER_N];
+        let mut b = [0; KYBER_N];
+        for i in 0..KYBER_N {
+            a[i] = (i as u64 * 17 + 5) % KYBER_Q;
+            b[i] = (i as u64 * i as u64 + 1000) % KYBER_Q;
+        }
+        let expected = negacyclic_schoolbook::<Kyber>(&a, &b);
+        let og = a;
+        kyber_ntt(&mut a);
+        kyber_ntt(&mut b);
+        let mut c = kyber_multiply_ntts(&a, &b);
+        kyber_intt(&mut c);
+        assert_eq!(c.to_vec(), expected);
+        kyber_intt(&mut a);
+        assert_eq!(a, og);
+    }
+}
@@ -0,0 +1,320 @@
+mod batch;
+mod bluestein;
+mod convolution;
+mod coset;
+mod crt;
+mod dif;
+mod dilithium;
+mod error;
+mod field;
+mod four_step;
+mod kyber;
+mod mixed_radix;
+mod modint;
+mod montgomery;
+mod multi_dim;
+mod negacyclic;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod plan;
+mod polynomial;
+mod shoup;
+mod simd;
+mod stockham;
+#[cfg(test)]
+mod testing;
+
+pub use batch::{
+    intt_batch, intt_batch_with, intt_columns, intt_columns_with, ntt_batch, ntt_batch_with,
+    ntt_columns, ntt_columns_with,
+};
+pub use bluestein::{bluestein_intt, bluestein_intt_with, bluestein_ntt, bluestein_ntt_with};
+pub use convolution::{convolve, convolve_with, multiply_polynomials};
+pub use coset::{
+    coset_intt, coset_intt_with, coset_ntt, coset_ntt_with, low_degree_extend,
+    low_degree_extend_with,
+};
+pub use crt::{convolve_exact_u128, convolve_mod};
+pub use dif::{
+    intt_dit_no_bitrev, intt_dit_no_bitrev_with, ntt_dif_no_bitrev, ntt_dif_no_bitrev_with,
+};
+pub use dilithium::{
+    dilithium_intt, dilithium_multiply_ntts, dilithium_ntt, Dilithium, DILITHIUM_N, DILITHIUM_Q,
+    DILITHIUM_ZETAS,
+};
+pub use error::NttError;
+pub use field::{
+    Goldilocks, NttField, F167772161, F4179340454199820289, F469762049, F754974721, F998244353,
+};
+pub use four_step::{FourStepPlan, FOUR_STEP_THRESHOLD};
+pub use kyber::{
+    kyber_basemul, kyber_intt, kyber_multiply_ntts, kyber_ntt, Kyber, KYBER_GAMMAS, KYBER_N,
+    KYBER_Q, KYBER_ZETAS,
+};
+pub use mixed_radix::MixedRadixPlan;
+pub use modint::ModInt;
+pub use montgomery::{Montgomery, MontgomeryPlan};
+pub use multi_dim::{
+    intt_2d, intt_2d_with, intt_nd, intt_nd_with, multiply_bivariate, multiply_bivariate_with,
+    ntt_2d, ntt_2d_with, ntt_nd, ntt_nd_with,
+};
+pub use negacyclic::{negacyclic_multiply, negacyclic_multiply_with, NegacyclicPlan};
+#[cfg(feature = "parallel")]
+pub use parallel::PARALLEL_THRESHOLD;
+pub use plan::{bit_reverse_permute, Kernel, NttPlan};
+pub use polynomial::{Polynomial, NTT_CUTOFF, SCHOOLBOOK_CUTOFF};
+pub use shoup::{shoup_mul, shoup_precompute, ShoupPlan};
+pub use simd::SimdPlan;
+pub use stockham::{intt_into, intt_into_with, ntt_into, ntt_into_with};
+
+/// Prime modulus for the NTT
+pub const MODULUS: u64 = F998244353::MODULUS;
+/// Primitive root of MODULUS
+pub const PRIMITIVE_ROOT: u64 = F998244353::GENERATOR;
+
+/// Compute (a + b) % modulus
+pub fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + b as u128) % modulus as u128) as u64
+}
+
+/// Compute (a - b) % modulus
+pub fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + modulus as u128 - (b % modulus) as u128) % modulus as u128) as u64
+}
+
+/// Compute (a * b) % modulus
+pub const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// Compute (base^exp) % modulus efficiently
+pub const fn power_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        exp >>= 1;
+        base = mul_mod(base, base, modulus);
+    }
+    result
+}
+
+/// Number Theoretic Transform (NTT) over `F` of the first `n` elements of `a`, in place
+pub fn ntt_with<F: NttField>(a: &mut [u64], n: usize, primitive_root: u64) {
+    let mut m = n;
+    let mut h = 0;
+    while m > 1 {
+        m >>= 1;
+        h += 1;
+    }
+    modint::reduce_all::<F>(&mut a[..n]);
+    let mut rev = vec![0; n];
+    for i in 0..n {
+        rev[i] = rev[i >> 1] >> 1 | (if i & 1 == 1 { n >> 1 } else { 0 });
+        if i < rev[i] {
+            a.swap(i, rev[i]);
+        }
+    }
+    for i in 1..=h {
+        let mh = 1 << i;
+        let m = mh >> 1;
+        let base = ModInt::<F>::new(primitive_root).pow((F::MODULUS - 1) / mh as u64);
+        #[cfg(feature = "parallel")]
+        if n >= PARALLEL_THRESHOLD {
+            let w: Vec<_> = std::iter::successors(Some(ModInt::<F>::one()), |&w| Some(w * base))
+                .take(m)
+                .collect();
+            parallel::radix2_stage(&mut a[..n], m, &w);
+            continue;
+        }
+        let mut w = ModInt::<F>::one();
+        for j in 0..m {
+            for k in (0..n).step_by(mh) {
+                let u = ModInt::<F>::from_reduced(a[k + j]);
+                let t = ModInt::<F>::from_reduced(a[k + j + m]) * w;
+                a[k + j] = (u + t).value();
+                a[k + j + m] = (u - t).value();
+            }
+            w *= base;
+        }
+    }
+}
+
+/// Inverse Number Theoretic Transform (NTT) over `F`
+pub fn intt_with<F: NttField>(a: &mut [u64], n: usize, primitive_root: u64) {
+    let n_inv = ModInt::<F>::new(n as u64).pow(F::MODULUS - 2);
+    ntt_with::<F>(a, n, power_mod(primitive_root, F::MODULUS - 2, F::MODULUS));
+    #[cfg(feature = "parallel")]
+    if a.len() >= PARALLEL_THRESHOLD {
+        parallel::scale(a, n_inv);
+        return;
+    }
+    for ai in a.iter_mut() {
+        *ai = (ModInt::<F>::new(*ai) * n_inv).value();
+    }
+}
+
+/// Number Theoretic Transform (NTT) of the first `n` elements of `a`, in place
+pub fn ntt(a: &mut [u64], n: usize, primitive_root: u64) {
+    ntt_with::<F998244353>(a, n, primitive_root);
+}
+
+/// Inverse Number Theoretic Transform (NTT)
+pub fn intt(a: &mut [u64], n: usize, primitive_root: u64) {
+    intt_with::<F998244353>(a, n, primitive_root);
+}
+
+// Check that `n` is a valid power-of-two transform length for `F`
+pub(crate) fn check_size<F: NttField>(n: usize) -> Result<(), NttError> {
+    if !n.is_power_of_two() {
+        return Err(NttError::NonPowerOfTwo(n));
+    }
+    if n as u64 > F::max_ntt_size() {
+        return Err(NttError::SizeExceedsMaxOrder {
+            size: n,
+            max: F::max_ntt_size(),
+        });
+    }
+    Ok(())
+}
+
+fn check_args<F: NttField>(a: &[u64], n: usize, primitive_root: u64) -> Result<(), NttError> {
+    check_size::<F>(n)?;
+    if a.len() != n {
+        return Err(NttError::LengthMismatch {
+            expected: n,
+            actual: a.len(),
+        });
+    }
+    if primitive_root.is_multiple_of(F::MODULUS) {
+        return Err(NttError::NonInvertible(primitive_root));
+    }
+    Ok(())
+}
+
+/// Validated [`ntt_with`]
+pub fn try_ntt_with<F: NttField>(
+    a: &mut [u64],
+    n: usize,
+    primitive_root: u64,
+) -> Result<(), NttError> {
+    check_args::<F>(a, n, primitive_root)?;
+    ntt_with::<F>(a, n, primitive_root);
+    Ok(())
+}
+
+/// Validated [`intt_with`]
+pub fn try_intt_with<F: NttField>(
+    a: &mut [u64],
+    n: usize,
+    primitive_root: u64,
+) -> Result<(), NttError> {
+    check_args::<F>(a, n, primitive_root)?;
+    intt_with::<F>(a, n, primitive_root);
+    Ok(())
+}
+
+/// Validated [`ntt`]
+pub fn try_ntt(a: &mut [u64], n: usize, primitive_root: u64) -> Result<(), NttError> {
+    try_ntt_with::<F998244353>(a, n, primitive_root)
+}
+
+/// Validated [`intt`]
+pub fn try_intt(a: &mut [u64], n: usize, primitive_root: u64) -> Result<(), NttError> {
+    try_intt_with::<F998244353>(a, n, primitive_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intt_inverts_ntt() {
+        let mut coefficients = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let og = coefficients.clone();
+        ntt(&mut coefficients, 8, PRIMITIVE_ROOT);
+        intt(&mut coefficients, 8, PRIMITIVE_ROOT);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn pointwise_product_is_cyclic_convolution() {
+        let n = 8;
+        let mut vec0: Vec<u64> = vec![4, 1, 4, 2, 1, 3, 5, 6];
+        let mut vec1: Vec<u64> = vec![6, 1, 8, 0, 3, 3, 9, 8];
+        ntt(&mut vec0, n, PRIMITIVE_ROOT);
+        ntt(&mut vec1, n, PRIMITIVE_ROOT);
+        let mut res: Vec<u64> = vec0
+            .iter()
+            .zip(&vec1)
+            .map(|(&x, &y)| (ModInt::<F998244353>::new(x) * ModInt::new(y)).value())
+            .collect();
+        intt(&mut res, n, PRIMITIVE_ROOT);
+        assert_eq!(res, vec![123, 120, 106, 92, 139, 144, 140, 124]);
+    }
+
+    #[test]
+    fn unreduced_inputs_transform_like_their_residues() {
+        // Inputs above the modulus are reduced once on entry
+        let n = 16;
+        let reduced: Vec<u64> = (0..n as u64).map(|i| i * 12345 % MODULUS).collect();
+        let mut expected = reduced.clone();
+        ntt(&mut expected, n, PRIMITIVE_ROOT);
+        let mut a: Vec<u64> = reduced.iter().map(|&x| x + 3 * MODULUS).collect();
+        ntt(&mut a, n, PRIMITIVE_ROOT);
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn large_transform_roundtrips() {
+        // Large enough to take the threaded path with `--features parallel`
+        let n = 1 << 15;
+        let og: Vec<u64> = (0..n as u64).map(|i| i * 7919 % MODULUS).collect();
+        let mut coefficients = og.clone();
+        ntt(&mut coefficients, n, PRIMITIVE_ROOT);
+
+        let plan: NttPlan = NttPlan::new(n);
+        let mut expected = og.clone();
+        plan.forward_dif(&mut expected);
+        bit_reverse_permute(&mut expected);
+        assert_eq!(coefficients, expected);
+
+        plan.inverse(&mut expected);
+        assert_eq!(expected, og);
+        intt(&mut coefficients, n, PRIMITIVE_ROOT);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn try_ntt_rejects_bad_arguments() {
+        let mut coefficients: Vec<u64> = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            try_ntt(&mut coefficients, 6, PRIMITIVE_ROOT),
+            Err(NttError::NonPowerOfTwo(6))
+        );
+        assert_eq!(
+            try_ntt(&mut coefficients, 4, PRIMITIVE_ROOT),
+            Err(NttError::LengthMismatch {
+                expected: 4,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            try_intt(&mut coefficients, 1 << 24, PRIMITIVE_ROOT),
+            Err(NttError::SizeExceedsMaxOrder {
+                size: 1 << 24,
+                max: 1 << 23
+            })
+        );
+        coefficients.truncate(4);
+        assert_eq!(
+            try_ntt(&mut coefficients, 4, MODULUS),
+            Err(NttError::NonInvertible(MODULUS))
+        );
+        assert!(try_ntt(&mut coefficients, 4, PRIMITIVE_ROOT).is_ok());
+        assert!(try_intt(&mut coefficients, 4, PRIMITIVE_ROOT).is_ok());
+        assert_eq!(coefficients, vec![1, 2, 3, 4]);
+    }
+}
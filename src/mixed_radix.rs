@@ -0,0 +1,350 @@
+use std::marker::PhantomData;
+
+use crate::modint::reduce_all;
+use crate::{ModInt, NttError, NttField, F998244353};
+
+/// Mixed-radix Cooley–Tukey plan for any `n` dividing `p - 1`
+///
+/// `n` is split into prime factors, with dedicated butterflies for radix 2, 3 and 5
+/// and a direct DFT for the remaining (small) primes. Like [`NttPlan`](crate::NttPlan),
+/// a transform is a digit-reversal permutation followed by one in-place pass per factor.
+#[derive(Clone, Debug)]
+pub struct MixedRadixPlan<F: NttField = F998244353> {
+    n: usize,
+    factors: Vec<usize>,
+    // Before the first pass `a[i]` takes `a[digit_rev[i]]`, applied in place one cycle
+    // at a time from each index in `cycles`
+    digit_rev: Vec<usize>,
+    cycles: Vec<usize>,
+    // Innermost (last) factor first, the order the passes run in
+    stages: Vec<Stage<F>>,
+    n_inv: ModInt<F>,
+    // `root^(n/3)`
+    w3: ModInt<F>,
+    // `(w^s + w^-s) / 2` and `(w^s - w^-s) / 2` for `w = root^(n/5)`, `s = 1, 2`
+    c5: [ModInt<F>; 2],
+    d5: [ModInt<F>; 2],
+    _field: PhantomData<F>,
+}
+
+// One pass of radix-`radix` butterflies combining `radix` transforms of length `m`
+#[derive(Clone, Debug)]
+struct Stage<F: NttField> {
+    radix: usize,
+    m: usize,
+    // `w^(q k)` for `w` of order `radix * m`, at `[k (radix - 1) + q - 1]`
+    twiddles: Vec<ModInt<F>>,
+    // `w^j` for `w` of order `radix`, only for radices without a dedicated butterfly
+    roots: Vec<ModInt<F>>,
+}
+
+impl<F: NttField> MixedRadixPlan<F> {
+    /// Build a plan for transforms of length `n`
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, which must divide `p - 1`
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        let root = F::root_of_unity(n as u64).ok_or(NttError::NoRootOfUnity(n))?;
+        let root = ModInt::<F>::new(root);
+        let factors = factorize(n);
+
+        let half = ModInt::<F>::new(2).inv().expect("p is odd");
+        let (mut c5, mut d5) = ([ModInt::zero(); 2], [ModInt::zero(); 2]);
+        if n.is_multiple_of(5) {
+            for s in 1..=2 {
+                let (w, w_inv) = (
+                    root.pow((s * n / 5) as u64),
+                    root.pow(((5 - s) * n / 5) as u64),
+                );
+                c5[s - 1] = (w + w_inv) * half;
+                d5[s - 1] = (w - w_inv) * half;
+            }
+        }
+        let w3 = if n.is_multiple_of(3) {
+            root.pow((n / 3) as u64)
+        } else {
+            ModInt::zero()
+        };
+
+        let mut stages = Vec::with_capacity(factors.len());
+        let mut m = 1;
+        for &radix in factors.iter().rev() {
+            let w = root.pow((n / (radix * m)) as u64);
+            let mut twiddles = Vec::with_capacity(m * (radix - 1));
+            let mut wk = ModInt::one();
+            for _ in 0..m {
+                let mut wqk = wk;
+                for _ in 1..radix {
+                    twiddles.push(wqk);
+                    wqk *= wk;
+                }
+                wk *= w;
+            }
+            let roots = if radix > 5 {
+                let wr = root.pow((n / radix) as u64);
+                std::iter::successors(Some(ModInt::one()), |&x| Some(x * wr))
+                    .take(radix)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            stages.push(Stage {
+                radix,
+                m,
+                twiddles,
+                roots,
+            });
+            m *= radix;
+        }
+
+        let digit_rev = digit_reverse_table(n, &factors);
+        Ok(Self {
+            n,
+            cycles: cycle_leaders(&digit_rev),
+            digit_rev,
+            factors,
+            stages,
+            w3,
+            c5,
+            d5,
+            n_inv: ModInt::new(n as u64)
+                .inv()
+                .ok_or(NttError::NonInvertible(n as u64))?,
+            _field: PhantomData,
+        })
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Radices used by the decomposition, outermost first
+    pub fn factors(&self) -> &[usize] {
+        &self.factors
+    }
+
+    /// Forward transform of `a` in place, natural order in and out
+    pub fn forward(&self, a: &mut [u64]) {
+        let a = &mut a[..self.n];
+        reduce_all::<F>(a);
+        self.permute(a);
+        for stage in &self.stages {
+            self.stage(a, stage);
+        }
+    }
+
+    /// Inverse transform of `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        // The inverse DFT is the forward one read at `-k`
+        self.forward(a);
+        a[1..self.n].reverse();
+        for ai in a[..self.n].iter_mut() {
+            *ai = (ModInt::<F>::from_reduced(*ai) * self.n_inv).value();
+        }
+    }
+
+    // Move every element to its digit-reversed slot by walking each cycle once
+    fn permute(&self, a: &mut [u64]) {
+        for &start in &self.cycles {
+            let first = a[start];
+            let mut i = start;
+            loop {
+                let j = self.digit_rev[i];
+                if j == start {
+                    a[i] = first;
+                    break;
+                }
+                a[i] = a[j];
+                i = j;
+            }
+        }
+    }
+
+    // Decimation in time: every block of `radix * m` holds `radix` finished transforms
+    // of length `m`, which the butterflies at offsets `k + q m` combine in place
+    fn stage(&self, a: &mut [u64], stage: &Stage<F>) {
+        match stage.radix {
+            2 => self.pass(a, stage, |[y0, y1]| [y0 + y1, y0 - y1]),
+            3 => self.pass(a, stage, |[y0, y1, y2]| {
+                // `w + w^2 = -1` leaves a single multiplication
+                let d = self.w3 * (y1 - y2);
+                [y0 + y1 + y2, y0 - y2 + d, y0 - y1 - d]
+            }),
+            5 => self.pass(a, stage, |[y0, y1, y2, y3, y4]| {
+                let (a1, b1) = (y1 + y4, y1 - y4);
+                let (a2, b2) = (y2 + y3, y2 - y3);
+                let [c1, c2] = self.c5;
+                let [d1, d2] = self.d5;
+                let (s1, t1) = (y0 + c1 * a1 + c2 * a2, d1 * b1 + d2 * b2);
+                let (s2, t2) = (y0 + c2 * a1 + c1 * a2, d2 * b1 - d1 * b2);
+                [y0 + a1 + a2, s1 + t1, s2 + t2, s2 - t2, s1 - t1]
+            }),
+            _ => self.dft_pass(a, stage),
+        }
+    }
+
+    fn pass<const R: usize>(
+        &self,
+        a: &mut [u64],
+        stage: &Stage<F>,
+        butterfly: impl Fn([ModInt<F>; R]) -> [ModInt<F>; R],
+    ) {
+        let m = stage.m;
+        for block in a.chunks_exact_mut(R * m) {
+            for (k, twiddles) in stage.twiddles.chunks_exact(R - 1).enumerate() {
+                let y = std::array::from_fn(|q| {
+                    let x = ModInt::from_reduced(block[k + q * m]);
+                    if q == 0 {
+                        x
+                    } else {
+                        x * twiddles[q - 1]
+                    }
+                });
+                for (q, x) in butterfly(y).into_iter().enumerate() {
+                    block[k + q * m] = x.value();
+                }
+            }
+        }
+    }
+
+    // Radices without a dedicated butterfly go through a direct DFT, whose inputs need
+    // a buffer; it is allocated once per pass, next to `O(n r)` multiplications
+    fn dft_pass(&self, a: &mut [u64], stage: &Stage<F>) {
+        let (r, m) = (stage.radix, stage.m);
+        let mut y = vec![ModInt::<F>::zero(); r];
+        for block in a.chunks_exact_mut(r * m) {
+            for (k, twiddles) in stage.twiddles.chunks_exact(r - 1).enumerate() {
+                y[0] = ModInt::from_reduced(block[k]);
+                for q in 1..r {
+                    y[q] = ModInt::from_reduced(block[k + q * m]) * twiddles[q - 1];
+                }
+                for s in 0..r {
+                    block[k + s * m] = y
+                        .iter()
+                        .enumerate()
+                        .fold(ModInt::zero(), |acc, (q, &yq)| {
+                            acc + yq * stage.roots[q * s % r]
+                        })
+                        .value();
+                }
+            }
+        }
+    }
+}
+
+// Source index of each position once the input is split by `factors[0]`, each part by
+// `factors[1]`, and so on down to single elements
+fn digit_reverse_table(n: usize, factors: &[usize]) -> Vec<usize> {
+    (0..n)
+        .map(|i| {
+            let (mut rest, mut len, mut stride, mut src) = (i, n, 1, 0);
+            for &r in factors {
+                len /= r;
+                src += rest / len * stride;
+                rest %= len;
+                stride *= r;
+            }
+            src
+        })
+        .collect()
+}
+
+// Smallest index of every cycle of `perm` longer than one
+fn cycle_leaders(perm: &[usize]) -> Vec<usize> {
+    let mut seen = vec![false; perm.len()];
+    let mut leaders = Vec::new();
+    for start in 0..perm.len() {
+        if seen[start] || perm[start] == start {
+            continue;
+        }
+        leaders.push(start);
+        let mut i = start;
+        while !seen[i] {
+            seen[i] = true;
+            i = perm[i];
+        }
+    }
+    leaders
+}
+
+// Prime factors of `n` in ascending order, with multiplicity
+fn factorize(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        while n.is_multiple_of(d) {
+            factors.push(d);
+            n /= d;
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::naive_dft;
+    use crate::{NttPlan, F754974721};
+
+    #[test]
+    fn mixed_radix_matches_the_definition() {
+        // 754974721 - 1 = 2^24 * 3^2 * 5
+        for n in [6, 12, 15, 45, 40] {
+            let og: Vec<u64> = (1..=n).collect();
+            let plan = MixedRadixPlan::<F754974721>::new(n as usize);
+            let mut coefficients = og.clone();
+            plan.forward(&mut coefficients);
+            assert_eq!(coefficients, naive_dft::<F754974721>(&og));
+            plan.inverse(&mut coefficients);
+            assert_eq!(coefficients, og);
+        }
+    }
+
+    #[test]
+    fn larger_sizes_match() {
+        // 720 = 2^4 * 3^2 * 5, with inputs above the modulus
+        let og: Vec<u64> = (0..720).map(|i| i * i + 7).collect();
+        let plan = MixedRadixPlan::<F754974721>::new(720);
+        let mut coefficients: Vec<u64> = og.iter().map(|&x| x + 2 * F754974721::MODULUS).collect();
+        plan.forward(&mut coefficients);
+        assert_eq!(coefficients, naive_dft::<F754974721>(&og));
+
+        let n = 1 << 10;
+        let og: Vec<u64> = (0..n as u64)
+            .map(|i| i * 7919 % F998244353::MODULUS)
+            .collect();
+        let mut expected = og.clone();
+        NttPlan::<F998244353>::new(n).forward(&mut expected);
+        let plan: MixedRadixPlan = MixedRadixPlan::new(n);
+        let mut coefficients = og.clone();
+        plan.forward(&mut coefficients);
+        assert_eq!(coefficients, expected);
+        plan.inverse(&mut coefficients);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn other_prime_factors_fall_back_to_direct_dfts() {
+        let og: Vec<u64> = (1..=119).collect();
+        let plan: MixedRadixPlan = MixedRadixPlan::new(119);
+        assert_eq!(plan.factors(), [7, 17]);
+        let mut coefficients = og.clone();
+        plan.forward(&mut coefficients);
+        assert_eq!(coefficients, naive_dft::<F998244353>(&og));
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{NttField, F998244353};
+
+/// An element of `F`, always kept reduced to `[0, p)`
+#[repr(transparent)]
+pub struct ModInt<F: NttField = F998244353> {
+    value: u64,
+    // `fn() -> F` keeps `ModInt` `Send + Sync` whatever the marker type
+    _field: PhantomData<fn() -> F>,
+}
+
+impl<F: NttField> ModInt<F> {
+    /// Reduce `value` modulo `p`
+    pub const fn new(value: u64) -> Self {
+        Self::from_reduced(value % F::MODULUS)
+    }
+
+    // `value` must already be in `[0, p)`
+    pub(crate) const fn from_reduced(value: u64) -> Self {
+        Self {
+            value,
+            _field: PhantomData,
+        }
+    }
+
+    pub const fn zero() -> Self {
+        Self::from_reduced(0)
+    }
+
+    pub const fn one() -> Self {
+        Self::from_reduced(1)
+    }
+
+    /// Canonical representative in `[0, p)`
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// `self^exp` by square-and-multiply
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            exp >>= 1;
+            base *= base;
+        }
+        result
+    }
+
+    /// Multiplicative inverse, `None` for zero
+    pub fn inv(self) -> Option<Self> {
+        if self.value == 0 {
+            return None;
+        }
+        Some(self.pow(F::MODULUS - 2))
+    }
+}
+
+// Bring every element into `[0, p)` once, so that transform loops can load them
+// with `ModInt::from_reduced` instead of paying for a `%` on every access
+pub(crate) fn reduce_all<F: NttField>(a: &mut [u64]) {
+    for x in a.iter_mut() {
+        if *x >= F::MODULUS {
+            *x %= F::MODULUS;
+        }
+    }
+}
+
+impl<F: NttField> Clone for ModInt<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: NttField> Copy for ModInt<F> {}
+
+impl<F: NttField> PartialEq for ModInt<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F: NttField> Eq for ModInt<F> {}
+
+impl<F: NttField> Hash for ModInt<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<F: NttField> Default for ModInt<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: NttField> fmt::Debug for ModInt<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<F: NttField> fmt::Display for ModInt<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<F: NttField> From<u64> for ModInt<F> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<F: NttField> From<ModInt<F>> for u64 {
+    fn from(x: ModInt<F>) -> Self {
+        x.value
+    }
+}
+
+// `p` if `cond`, else 0; a mask instead of a branch, since in butterflies the
+// corrections in `Add` and `Sub` are needed at random
+#[inline]
+fn modulus_if<F: NttField>(cond: bool) -> u64 {
+    F::MODULUS & (cond as u64).wrapping_neg()
+}
+
+impl<F: NttField> Add for ModInt<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Both operands are below p, so one subtraction suffices even if the sum wraps;
+        // `p` is added back when the sum was already reduced
+        let (sum, overflow) = self.value.overflowing_add(rhs.value);
+        let (reduced, borrow) = sum.overflowing_sub(F::MODULUS);
+        let keep_sum = borrow & !overflow;
+        Self::from_reduced(reduced.wrapping_add(modulus_if::<F>(keep_sum)))
+    }
+}
+
+impl<F: NttField> Sub for ModInt<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (diff, borrow) = self.value.overflowing_sub(rhs.value);
+        Self::from_reduced(diff.wrapping_add(modulus_if::<F>(borrow)))
+    }
+}
+
+impl<F: NttField> Mul for ModInt<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_reduced(F::reduce_wide(self.value as u128 * rhs.value as u128))
+    }
+}
+
+impl<F: NttField> Neg for ModInt<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<F: NttField> AddAssign for ModInt<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: NttField> SubAssign for ModInt<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: NttField> MulAssign for ModInt<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Goldilocks;
+
+    #[test]
+    fn arithmetic_near_the_modulus() {
+        let minus_one = -ModInt::<Goldilocks>::one();
+        assert_eq!(minus_one.value(), Goldilocks::MODULUS - 1);
+        assert_eq!((minus_one + minus_one).value(), Goldilocks::MODULUS - 2);
+        assert_eq!(minus_one * minus_one, ModInt::one());
+        let two = ModInt::<Goldilocks>::new(2);
+        assert_eq!(two * two.inv().unwrap(), ModInt::one());
+        assert_eq!(ModInt::<Goldilocks>::zero().inv(), None);
+    }
+}
@@ -0,0 +1,205 @@
+use std::marker::PhantomData;
+
+use crate::plan::permute;
+use crate::{ModInt, NttError, NttField, NttPlan, F998244353};
+
+/// Montgomery arithmetic over `F` with `R = 2^64`
+///
+/// Values in Montgomery form are `x * R mod p`, kept in `[0, p)`.
+pub struct Montgomery<F: NttField = F998244353>(PhantomData<F>);
+
+impl<F: NttField> Montgomery<F> {
+    /// `p^-1 mod 2^64`
+    pub const P_INV: u64 = inv_mod_2_64(F::MODULUS);
+    /// `R^2 mod p`, used to convert into Montgomery form
+    pub const R2: u64 = {
+        let r = ((1u128 << 64) % F::MODULUS as u128) as u64;
+        ((r as u128 * r as u128) % F::MODULUS as u128) as u64
+    };
+
+    /// `t * R^-1 mod p` for `t < p * R`
+    #[inline]
+    pub fn reduce(t: u128) -> u64 {
+        let lo = t as u64;
+        let hi = (t >> 64) as u64;
+        // `m * p` agrees with `t` in the low word, so only the high words need subtracting
+        let m = lo.wrapping_mul(Self::P_INV);
+        let mp_hi = ((m as u128 * F::MODULUS as u128) >> 64) as u64;
+        let (r, borrow) = hi.overflowing_sub(mp_hi);
+        if borrow {
+            r.wrapping_add(F::MODULUS)
+        } else {
+            r
+        }
+    }
+
+    /// Convert `x` into Montgomery form
+    pub fn to_montgomery(x: u64) -> u64 {
+        Self::reduce((x % F::MODULUS) as u128 * Self::R2 as u128)
+    }
+
+    /// Convert `x` out of Montgomery form
+    pub fn from_montgomery(x: u64) -> u64 {
+        Self::reduce(x as u128)
+    }
+
+    /// Product of two Montgomery-form values
+    #[inline]
+    pub fn mul(a: u64, b: u64) -> u64 {
+        Self::reduce(a as u128 * b as u128)
+    }
+
+    /// Sum of two Montgomery-form values
+    #[inline]
+    pub fn add(a: u64, b: u64) -> u64 {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= F::MODULUS {
+            sum.wrapping_sub(F::MODULUS)
+        } else {
+            sum
+        }
+    }
+
+    /// Difference of two Montgomery-form values
+    #[inline]
+    pub fn sub(a: u64, b: u64) -> u64 {
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            diff.wrapping_add(F::MODULUS)
+        } else {
+            diff
+        }
+    }
+}
+
+// Newton iteration, each step doubles the number of correct low bits
+const fn inv_mod_2_64(p: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
+
+/// Size-`n` transform plan whose butterflies run in the Montgomery domain
+#[derive(Clone, Debug)]
+pub struct MontgomeryPlan<F: NttField = F998244353> {
+    n: usize,
+    rev: Vec<usize>,
+    twiddles: Vec<u64>,
+    inv_twiddles: Vec<u64>,
+    n_inv: u64,
+    _field: PhantomData<F>,
+}
+
+impl<F: NttField> MontgomeryPlan<F> {
+    /// Build a plan for transforms of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, validating the size
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        let plan = NttPlan::<F>::try_new(n)?;
+        let to_montgomery = |w: &ModInt<F>| Montgomery::<F>::to_montgomery(w.value());
+        Ok(Self {
+            n,
+            twiddles: plan.twiddles.iter().map(to_montgomery).collect(),
+            inv_twiddles: plan.inv_twiddles.iter().map(to_montgomery).collect(),
+            n_inv: to_montgomery(&plan.n_inv),
+            rev: plan.rev,
+            _field: PhantomData,
+        })
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Convert `a` into Montgomery form in place
+    pub fn to_montgomery(&self, a: &mut [u64]) {
+        for ai in a.iter_mut() {
+            *ai = Montgomery::<F>::to_montgomery(*ai);
+        }
+    }
+
+    /// Convert `a` out of Montgomery form in place
+    pub fn from_montgomery(&self, a: &mut [u64]) {
+        for ai in a.iter_mut() {
+            *ai = Montgomery::<F>::from_montgomery(*ai);
+        }
+    }
+
+    /// Forward transform of Montgomery-form `a` in place
+    pub fn forward(&self, a: &mut [u64]) {
+        self.transform(a, &self.twiddles);
+    }
+
+    /// Inverse transform of Montgomery-form `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        self.transform(a, &self.inv_twiddles);
+        for ai in a[..self.n].iter_mut() {
+            *ai = Montgomery::<F>::mul(*ai, self.n_inv);
+        }
+    }
+
+    fn transform(&self, a: &mut [u64], twiddles: &[u64]) {
+        let n = self.n;
+        let a = &mut a[..n];
+        permute(a, &self.rev);
+        let mut m = 1;
+        while m < n {
+            let w = &twiddles[m..2 * m];
+            for k in (0..n).step_by(2 * m) {
+                for j in 0..m {
+                    let u = a[k + j];
+                    let t = Montgomery::<F>::mul(a[k + j + m], w[j]);
+                    a[k + j] = Montgomery::<F>::add(u, t);
+                    a[k + j + m] = Montgomery::<F>::sub(u, t);
+                }
+            }
+            m <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt_with, Goldilocks};
+
+    fn roundtrip<F: NttField>() {
+        let og: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut expected = og.clone();
+        ntt_with::<F>(&mut expected, 8, F::GENERATOR);
+
+        let plan = MontgomeryPlan::<F>::new(8);
+        let mut coefficients = og.clone();
+        plan.to_montgomery(&mut coefficients);
+        plan.forward(&mut coefficients);
+        let mut transformed = coefficients.clone();
+        plan.from_montgomery(&mut transformed);
+        assert_eq!(transformed, expected);
+
+        plan.inverse(&mut coefficients);
+        plan.from_montgomery(&mut coefficients);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn montgomery_plan_matches_ntt() {
+        roundtrip::<F998244353>();
+        roundtrip::<Goldilocks>();
+    }
+}
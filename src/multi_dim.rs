@@ -0,0 +1,215 @@
+use crate::batch::{forward_columns, inverse_columns};
+use crate::{check_size, ModInt, NttError, NttField, NttPlan, F998244353};
+
+fn check_shape<F: NttField>(data: &[u64], shape: &[usize]) -> Result<(), NttError> {
+    for &n in shape {
+        check_size::<F>(n)?;
+    }
+    let len = shape.iter().product();
+    if data.len() != len {
+        return Err(NttError::LengthMismatch {
+            expected: len,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+// One axis at a time: the last axis is a run of contiguous 1D transforms, every other
+// axis is a set of column transforms over blocks of `n * inner` elements
+fn transform_axes<F: NttField>(data: &mut [u64], shape: &[usize], inverse: bool) {
+    for (d, &n) in shape.iter().enumerate() {
+        let inner: usize = shape[d + 1..].iter().product();
+        let plan = NttPlan::<F>::new(n);
+        for block in data.chunks_mut(n * inner) {
+            match (inner, inverse) {
+                (1, false) => plan.forward(block),
+                (1, true) => plan.inverse(block),
+                (_, false) => forward_columns(&plan, block, inner),
+                (_, true) => inverse_columns(&plan, block, inner),
+            }
+        }
+    }
+}
+
+/// N-dimensional NTT over `F` of the row-major array `data` with dimensions `shape`,
+/// in place
+///
+/// Every dimension must be a valid transform length for `F`, and `data` must hold
+/// exactly their product elements.
+pub fn ntt_nd_with<F: NttField>(data: &mut [u64], shape: &[usize]) -> Result<(), NttError> {
+    check_shape::<F>(data, shape)?;
+    transform_axes::<F>(data, shape, false);
+    Ok(())
+}
+
+/// Inverse of [`ntt_nd_with`], including the scaling by the inverse of every dimension
+pub fn intt_nd_with<F: NttField>(data: &mut [u64], shape: &[usize]) -> Result<(), NttError> {
+    check_shape::<F>(data, shape)?;
+    transform_axes::<F>(data, shape, true);
+    Ok(())
+}
+
+/// 2D NTT over `F` of the row-major `rows x cols` matrix `data`: columns, then rows
+pub fn ntt_2d_with<F: NttField>(
+    data: &mut [u64],
+    rows: usize,
+    cols: usize,
+) -> Result<(), NttError> {
+    ntt_nd_with::<F>(data, &[rows, cols])
+}
+
+/// Inverse of [`ntt_2d_with`]
+pub fn intt_2d_with<F: NttField>(
+    data: &mut [u64],
+    rows: usize,
+    cols: usize,
+) -> Result<(), NttError> {
+    intt_nd_with::<F>(data, &[rows, cols])
+}
+
+/// N-dimensional NTT modulo `MODULUS`; see [`ntt_nd_with`]
+pub fn ntt_nd(data: &mut [u64], shape: &[usize]) -> Result<(), NttError> {
+    ntt_nd_with::<F998244353>(data, shape)
+}
+
+/// Inverse N-dimensional NTT modulo `MODULUS`
+pub fn intt_nd(data: &mut [u64], shape: &[usize]) -> Result<(), NttError> {
+    intt_nd_with::<F998244353>(data, shape)
+}
+
+/// 2D NTT modulo `MODULUS`; see [`ntt_2d_with`]
+pub fn ntt_2d(data: &mut [u64], rows: usize, cols: usize) -> Result<(), NttError> {
+    ntt_2d_with::<F998244353>(data, rows, cols)
+}
+
+/// Inverse 2D NTT modulo `MODULUS`
+pub fn intt_2d(data: &mut [u64], rows: usize, cols: usize) -> Result<(), NttError> {
+    intt_2d_with::<F998244353>(data, rows, cols)
+}
+
+// Copy a row-major `rows x cols` matrix into the top-left corner of a zeroed
+// `rows_out x cols_out` one
+fn pad(a: &[u64], cols: usize, rows_out: usize, cols_out: usize) -> Vec<u64> {
+    let mut out = vec![0; rows_out * cols_out];
+    for (src, dst) in a.chunks(cols).zip(out.chunks_mut(cols_out)) {
+        dst[..cols].copy_from_slice(src);
+    }
+    out
+}
+
+/// Product of two bivariate polynomials over `F`
+///
+/// A polynomial with shape `(rows, cols)` is a row-major coefficient matrix whose
+/// entry `i * cols + j` is the coefficient of `x^i y^j`. The product has shape
+/// `(a_rows + b_rows - 1, a_cols + b_cols - 1)`, or is empty if either input is.
+pub fn multiply_bivariate_with<F: NttField>(
+    a: &[u64],
+    a_shape: (usize, usize),
+    b: &[u64],
+    b_shape: (usize, usize),
+) -> Result<Vec<u64>, NttError> {
+    for (p, (rows, cols)) in [(a, a_shape), (b, b_shape)] {
+        if p.len() != rows * cols {
+            return Err(NttError::LengthMismatch {
+                expected: rows * cols,
+                actual: p.len(),
+            });
+        }
+    }
+    if a.is_empty() || b.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rows = a_shape.0 + b_shape.0 - 1;
+    let cols = a_shape.1 + b_shape.1 - 1;
+    let shape = [rows.next_power_of_two(), cols.next_power_of_two()];
+
+    let mut fa = pad(a, a_shape.1, shape[0], shape[1]);
+    let mut fb = pad(b, b_shape.1, shape[0], shape[1]);
+    ntt_nd_with::<F>(&mut fa, &shape)?;
+    ntt_nd_with::<F>(&mut fb, &shape)?;
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x = (ModInt::<F>::new(*x) * ModInt::new(*y)).value();
+    }
+    intt_nd_with::<F>(&mut fa, &shape)?;
+    Ok(fa
+        .chunks(shape[1])
+        .take(rows)
+        .flat_map(|row| &row[..cols])
+        .copied()
+        .collect())
+}
+
+/// Product of two bivariate polynomials modulo `MODULUS`; see [`multiply_bivariate_with`]
+pub fn multiply_bivariate(
+    a: &[u64],
+    a_shape: (usize, usize),
+    b: &[u64],
+    b_shape: (usize, usize),
+) -> Result<Vec<u64>, NttError> {
+    multiply_bivariate_with::<F998244353>(a, a_shape, b, b_shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{add_mod, mul_mod, power_mod, MODULUS, PRIMITIVE_ROOT};
+
+    #[test]
+    fn ntt_2d_matches_the_definition() {
+        // sum of a[i][j] w_r^(i u) w_c^(j v)
+        let (rows, cols) = (4, 8);
+        let data: Vec<u64> = (0..(rows * cols) as u64).map(|i| i * i + 1).collect();
+        let mut transformed = data.clone();
+        assert!(ntt_2d(&mut transformed, rows, cols).is_ok());
+        let wr = power_mod(PRIMITIVE_ROOT, (MODULUS - 1) / rows as u64, MODULUS);
+        let wc = power_mod(PRIMITIVE_ROOT, (MODULUS - 1) / cols as u64, MODULUS);
+        for u in 0..rows {
+            for v in 0..cols {
+                let mut sum = 0;
+                for i in 0..rows {
+                    for j in 0..cols {
+                        let w = mul_mod(
+                            power_mod(wr, (i * u) as u64, MODULUS),
+                            power_mod(wc, (j * v) as u64, MODULUS),
+                            MODULUS,
+                        );
+                        sum = add_mod(sum, mul_mod(data[i * cols + j], w, MODULUS), MODULUS);
+                    }
+                }
+                assert_eq!(transformed[u * cols + v], sum);
+            }
+        }
+        assert!(intt_2d(&mut transformed, rows, cols).is_ok());
+        assert_eq!(transformed, data);
+    }
+
+    #[test]
+    fn ntt_nd_roundtrips() {
+        let shape = [2, 4, 8, 2];
+        let data: Vec<u64> = (0..128).map(|i| i * 31 % 17).collect();
+        let mut transformed = data.clone();
+        assert!(ntt_nd(&mut transformed, &shape).is_ok());
+        assert_ne!(transformed, data);
+        assert!(intt_nd(&mut transformed, &shape).is_ok());
+        assert_eq!(transformed, data);
+        assert_eq!(
+            ntt_nd(&mut [0; 12], &[4, 3]),
+            Err(NttError::NonPowerOfTwo(3))
+        );
+    }
+
+    #[test]
+    fn bivariate_product() {
+        // (1 + 2y + 3x)(4 + 5xy) = 4 + 8y + 12x + 5xy + 10xy^2 + 15x^2y
+        let product = multiply_bivariate(&[1, 2, 3, 0], (2, 2), &[4, 0, 0, 5], (2, 2));
+        assert_eq!(product, Ok(vec![4, 8, 0, 12, 5, 10, 0, 15, 0]));
+        assert_eq!(
+            multiply_bivariate(&[1, 2, 3], (2, 2), &[1], (1, 1)),
+            Err(NttError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{check_size, ModInt, NttError, NttField, NttPlan, F998244353};
+
+/// Size-`n` transform plan for `Z_p[X]/(X^n + 1)`
+///
+/// Twists by powers of a primitive `2n`-th root `psi` around the cyclic transform, so
+/// pointwise products of transformed inputs give negacyclic convolutions.
+#[derive(Clone, Debug)]
+pub struct NegacyclicPlan<F: NttField = F998244353> {
+    plan: NttPlan<F>,
+    psi_powers: Vec<ModInt<F>>,
+    psi_inv_powers: Vec<ModInt<F>>,
+}
+
+impl<F: NttField> NegacyclicPlan<F> {
+    /// Build a plan for polynomials of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for length `n`, which needs a `2n`-th root of unity in `F`
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        check_size::<F>(n)?;
+        check_size::<F>(2 * n)?;
+        let psi = ModInt::<F>::new(F::root_of_unity(2 * n as u64).expect("size was checked"));
+        let psi_inv = psi.inv().expect("roots of unity are invertible");
+        let powers = |base: ModInt<F>| {
+            let mut w = ModInt::one();
+            (0..n)
+                .map(|_| {
+                    let x = w;
+                    w *= base;
+                    x
+                })
+                .collect()
+        };
+        Ok(Self {
+            plan: NttPlan::try_new(n)?,
+            psi_powers: powers(psi),
+            psi_inv_powers: powers(psi_inv),
+        })
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.plan.len()
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.plan.is_empty()
+    }
+
+    /// Forward negacyclic transform of `a` in place, natural order out
+    pub fn forward(&self, a: &mut [u64]) {
+        self.twist(a, &self.psi_powers);
+        self.plan.forward(a);
+    }
+
+    /// Inverse of [`NegacyclicPlan::forward`]
+    pub fn inverse(&self, a: &mut [u64]) {
+        self.plan.inverse(a);
+        self.twist(a, &self.psi_inv_powers);
+    }
+
+    /// Product of `a` and `b` in `Z_p[X]/(X^n + 1)`
+    pub fn multiply(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        assert_eq!(a.len(), self.len(), "operand length must match the plan");
+        assert_eq!(b.len(), self.len(), "operand length must match the plan");
+        let mut fa = a.to_vec();
+        let mut fb = b.to_vec();
+        // Pointwise products don't care about order, so the bit reversals are skipped
+        self.twist(&mut fa, &self.psi_powers);
+        self.twist(&mut fb, &self.psi_powers);
+        self.plan.forward_dif(&mut fa);
+        self.plan.forward_dif(&mut fb);
+        for (x, y) in fa.iter_mut().zip(&fb) {
+            *x = (ModInt::<F>::new(*x) * ModInt::new(*y)).value();
+        }
+        self.plan.inverse_dit(&mut fa);
+        self.twist(&mut fa, &self.psi_inv_powers);
+        fa
+    }
+
+    fn twist(&self, a: &mut [u64], powers: &[ModInt<F>]) {
+        for (ai, &w) in a.iter_mut().zip(powers) {
+            *ai = (ModInt::<F>::new(*ai) * w).value();
+        }
+    }
+}
+
+/// Product of `a` and `b` in `Z_p[X]/(X^n + 1)` over `F`, with `n = a.len() = b.len()`
+pub fn negacyclic_multiply_with<F: NttField>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    NegacyclicPlan::<F>::new(a.len()).multiply(a, b)
+}
+
+/// Product of `a` and `b` in `Z_p[X]/(X^n + 1)` modulo `MODULUS`
+pub fn negacyclic_multiply(a: &[u64], b: &[u64]) -> Vec<u64> {
+    negacyclic_multiply_with::<F998244353>(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::negacyclic_schoolbook;
+    use crate::{Goldilocks, MODULUS};
+
+    #[test]
+    fn negacyclic_matches_schoolbook() {
+        for n in [1, 2, 8, 64] {
+            let a: Vec<u64> = (0..n as u64).map(|i| (i * 7919 + 3) % MODULUS).collect();
+            let b: Vec<u64> = (0..n as u64).map(|i| MODULUS - 1 - i * i).collect();
+            let expected = negacyclic_schoolbook::<F998244353>(&a, &b);
+            assert_eq!(negacyclic_multiply(&a, &b), expected);
+            assert_eq!(
+                negacyclic_multiply_with::<Goldilocks>(&a, &b),
+                negacyclic_schoolbook::<Goldilocks>(&a, &b)
+            );
+
+            let plan: NegacyclicPlan = NegacyclicPlan::new(n);
+            let mut coefficients = a.clone();
+            plan.forward(&mut coefficients);
+            plan.inverse(&mut coefficients);
+            assert_eq!(coefficients, a);
+        }
+    }
+}
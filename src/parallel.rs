@@ -0,0 +1,65 @@
+use rayon::prelude::*;
+
+use crate::{ModInt, NttField};
+
+/// Transforms of at least this length spread their stages over the rayon thread pool
+pub const PARALLEL_THRESHOLD: usize = 1 << 14;
+
+// Smallest run of butterflies or scalings handed to a single task
+const MIN_TASK_LEN: usize = 1 << 10;
+
+// One radix-2 stage with half-size `m`: the `2m`-blocks are independent, and so are
+// the butterflies inside a block, which keeps the last few stages parallel too
+pub(crate) fn radix2_stage<F: NttField>(a: &mut [u64], m: usize, w: &[ModInt<F>]) {
+    a.par_chunks_mut(2 * m).for_each(|block| {
+        let (lo, hi) = block.split_at_mut(m);
+        lo.par_iter_mut()
+            .zip(hi.par_iter_mut())
+            .zip(w.par_iter())
+            .with_min_len(MIN_TASK_LEN)
+            .for_each(|((x, y), &w)| {
+                let u = ModInt::<F>::from_reduced(*x);
+                let t = ModInt::<F>::from_reduced(*y) * w;
+                *x = (u + t).value();
+                *y = (u - t).value();
+            });
+    });
+}
+
+// Multiply every element by `factor`, e.g. the final `n^-1` of an inverse transform
+pub(crate) fn scale<F: NttField>(a: &mut [u64], factor: ModInt<F>) {
+    a.par_iter_mut()
+        .with_min_len(MIN_TASK_LEN)
+        .for_each(|x| *x = (ModInt::<F>::new(*x) * factor).value());
+}
+
+// Run `f(i, row)` on every `len`-element row `i` of `data`, one row per task
+pub(crate) fn for_each_row(data: &mut [u64], len: usize, f: impl Fn(usize, &mut [u64]) + Sync) {
+    data.par_chunks_mut(len)
+        .enumerate()
+        .for_each(|(i, row)| f(i, row));
+}
+
+// Run `f` on every vector of a batch, one vector per task
+pub(crate) fn for_each_vector(batch: &mut [Vec<u64>], f: impl Fn(&mut [u64]) + Sync) {
+    batch.par_iter_mut().for_each(|v| f(v));
+}
+
+// One radix-2 stage of column transforms over a row-major matrix with `cols` columns:
+// same split as `radix2_stage`, with whole rows in place of single elements
+pub(crate) fn column_stage<F: NttField>(data: &mut [u64], cols: usize, m: usize, w: &[ModInt<F>]) {
+    data.par_chunks_mut(2 * m * cols).for_each(|block| {
+        let (lo, hi) = block.split_at_mut(m * cols);
+        lo.par_chunks_mut(cols)
+            .zip(hi.par_chunks_mut(cols))
+            .zip(w.par_iter())
+            .for_each(|((x, y), &w)| {
+                for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+                    let u = ModInt::<F>::from_reduced(*x);
+                    let t = ModInt::<F>::from_reduced(*y) * w;
+                    *x = (u + t).value();
+                    *y = (u - t).value();
+                }
+            });
+    });
+}
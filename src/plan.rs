@@ -0,0 +1,395 @@
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use crate::modint::reduce_all;
+use crate::{
+    check_size, FourStepPlan, ModInt, NttError, NttField, F998244353, FOUR_STEP_THRESHOLD,
+};
+#[cfg(feature = "parallel")]
+use crate::{parallel, PARALLEL_THRESHOLD};
+
+/// Butterfly kernel used by an [`NttPlan`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Kernel {
+    /// One stage per pass, one twiddle multiplication per butterfly
+    #[default]
+    Radix2,
+    /// Two stages per pass, four multiplications per four outputs
+    ///
+    /// Three of them are twiddles and one is the 4th root of unity `i`, which in a
+    /// prime field is an ordinary modular multiplication, so a general butterfly
+    /// costs as much as two radix-2 stages. The savings are the halved number of
+    /// passes over memory and the butterflies at offset 0 in each block, where every
+    /// twiddle is 1 and only the multiplication by `i` is left; that covers the
+    /// whole first pass, for about 1/7 fewer multiplications at `n = 2^14`.
+    Radix4,
+}
+
+/// Precomputed tables for repeated size-`n` transforms over `F`
+#[derive(Clone, Debug)]
+pub struct NttPlan<F: NttField = F998244353> {
+    pub(crate) n: usize,
+    pub(crate) rev: Vec<usize>,
+    // Stage with half-size `m` keeps its `m` twiddles at `[m, 2m)`
+    pub(crate) twiddles: Vec<ModInt<F>>,
+    pub(crate) inv_twiddles: Vec<ModInt<F>>,
+    pub(crate) n_inv: ModInt<F>,
+    kernel: Kernel,
+    // Cubes of the twiddles, only built for `Kernel::Radix4`
+    cubed_twiddles: Vec<ModInt<F>>,
+    inv_cubed_twiddles: Vec<ModInt<F>>,
+    // Whether `forward` and `inverse` go through `four_step`, by default from
+    // `FOUR_STEP_THRESHOLD` up; the plan and its transpose buffer are only built on
+    // first use, since DIF/DIT and the plans that borrow these tables never need them
+    use_four_step: bool,
+    four_step: OnceLock<Box<FourStepPlan<F>>>,
+    _field: PhantomData<fn() -> F>,
+}
+
+impl<F: NttField> NttPlan<F> {
+    /// Build a plan for transforms of length `n` (a power of two)
+    ///
+    /// Panics if `n` is not a valid transform length for `F`; see [`NttPlan::try_new`].
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, validating the size
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        check_size::<F>(n)?;
+        let root = F::root_of_unity(n as u64).expect("size was checked against the two-adicity");
+        let root = ModInt::<F>::new(root);
+        let inv_root = root.inv().expect("roots of unity are invertible");
+        Ok(Self {
+            n,
+            rev: bit_reverse_table(n),
+            twiddles: stage_twiddles::<F>(root, n),
+            inv_twiddles: stage_twiddles::<F>(inv_root, n),
+            n_inv: ModInt::new(n as u64).inv().expect("n is below p"),
+            kernel: Kernel::Radix2,
+            cubed_twiddles: Vec::new(),
+            inv_cubed_twiddles: Vec::new(),
+            use_four_step: n >= FOUR_STEP_THRESHOLD,
+            four_step: OnceLock::new(),
+            _field: PhantomData,
+        })
+    }
+
+    /// Select the butterfly kernel; all kernels produce identical output
+    pub fn with_kernel(mut self, kernel: Kernel) -> Self {
+        let cube = |t: &[ModInt<F>]| t.iter().map(|&w| w * w * w).collect();
+        (self.cubed_twiddles, self.inv_cubed_twiddles) = match kernel {
+            Kernel::Radix2 => (Vec::new(), Vec::new()),
+            Kernel::Radix4 => (cube(&self.twiddles), cube(&self.inv_twiddles)),
+        };
+        self.kernel = kernel;
+        self.four_step = OnceLock::new();
+        self
+    }
+
+    /// Choose whether `forward` and `inverse` use the four-step algorithm
+    ///
+    /// By default they do from [`FOUR_STEP_THRESHOLD`] up. Four-step output is identical;
+    /// its first transform builds a transpose buffer of `n` elements that the plan keeps.
+    pub fn with_four_step(mut self, enabled: bool) -> Self {
+        self.use_four_step = enabled;
+        self.four_step = OnceLock::new();
+        self
+    }
+
+    fn four_step(&self) -> Option<&FourStepPlan<F>> {
+        if !self.use_four_step {
+            return None;
+        }
+        let plan = self.four_step.get_or_init(|| {
+            let plan = FourStepPlan::try_new(self.n).expect("size was checked");
+            Box::new(plan.with_kernel(self.kernel))
+        });
+        Some(plan)
+    }
+
+    /// Butterfly kernel in use
+    pub fn kernel(&self) -> Kernel {
+        self.kernel
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// `n^-1 mod p`
+    pub fn n_inv(&self) -> u64 {
+        self.n_inv.value()
+    }
+
+    /// Forward transform of `a` in place
+    pub fn forward(&self, a: &mut [u64]) {
+        if let Some(plan) = self.four_step() {
+            return plan.forward(a);
+        }
+        self.transform(a, &self.twiddles, &self.cubed_twiddles);
+    }
+
+    /// Inverse transform of `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        if let Some(plan) = self.four_step() {
+            return plan.inverse(a);
+        }
+        self.transform(a, &self.inv_twiddles, &self.inv_cubed_twiddles);
+        self.scale_by_n_inv(a);
+    }
+
+    /// Forward transform by decimation in frequency, leaving the output in bit-reversed order
+    pub fn forward_dif(&self, a: &mut [u64]) {
+        let n = self.n;
+        let a = &mut a[..n];
+        reduce_all::<F>(a);
+        let mut m = n >> 1;
+        while m >= 1 {
+            let w = &self.twiddles[m..2 * m];
+            for k in (0..n).step_by(2 * m) {
+                for j in 0..m {
+                    let u = ModInt::<F>::from_reduced(a[k + j]);
+                    let v = ModInt::<F>::from_reduced(a[k + j + m]);
+                    a[k + j] = (u + v).value();
+                    a[k + j + m] = ((u - v) * w[j]).value();
+                }
+            }
+            m >>= 1;
+        }
+    }
+
+    /// Inverse transform by decimation in time of bit-reversed input, including the
+    /// `n^-1` scaling; undoes [`NttPlan::forward_dif`] without any permutation
+    pub fn inverse_dit(&self, a: &mut [u64]) {
+        reduce_all::<F>(&mut a[..self.n]);
+        self.dit_stages(
+            &mut a[..self.n],
+            &self.inv_twiddles,
+            &self.inv_cubed_twiddles,
+        );
+        self.scale_by_n_inv(a);
+    }
+
+    fn scale_by_n_inv(&self, a: &mut [u64]) {
+        let a = &mut a[..self.n];
+        #[cfg(feature = "parallel")]
+        if self.n >= PARALLEL_THRESHOLD {
+            parallel::scale(a, self.n_inv);
+            return;
+        }
+        for ai in a.iter_mut() {
+            *ai = (ModInt::<F>::from_reduced(*ai) * self.n_inv).value();
+        }
+    }
+
+    fn transform(&self, a: &mut [u64], twiddles: &[ModInt<F>], cubed: &[ModInt<F>]) {
+        let a = &mut a[..self.n];
+        reduce_all::<F>(a);
+        permute(a, &self.rev);
+        self.dit_stages(a, twiddles, cubed);
+    }
+
+    fn dit_stages(&self, a: &mut [u64], twiddles: &[ModInt<F>], cubed: &[ModInt<F>]) {
+        let n = self.n;
+        let mut m = 1;
+        if self.kernel == Kernel::Radix4 {
+            // An odd number of stages leaves one radix-2 pass up front
+            if n.trailing_zeros() % 2 == 1 {
+                radix2_stage(a, 1, twiddles);
+                m = 2;
+            }
+            while m < n {
+                radix4_stage(a, m, twiddles, cubed);
+                m <<= 2;
+            }
+        } else {
+            while m < n {
+                radix2_stage(a, m, twiddles);
+                m <<= 1;
+            }
+        }
+    }
+}
+
+// Butterflies between elements `m` apart, in blocks of `2m`
+fn radix2_stage<F: NttField>(a: &mut [u64], m: usize, twiddles: &[ModInt<F>]) {
+    let w = &twiddles[m..2 * m];
+    #[cfg(feature = "parallel")]
+    if a.len() >= PARALLEL_THRESHOLD {
+        parallel::radix2_stage(a, m, w);
+        return;
+    }
+    for k in (0..a.len()).step_by(2 * m) {
+        for j in 0..m {
+            let u = ModInt::<F>::from_reduced(a[k + j]);
+            let t = ModInt::<F>::from_reduced(a[k + j + m]) * w[j];
+            a[k + j] = (u + t).value();
+            a[k + j + m] = (u - t).value();
+        }
+    }
+}
+
+// The radix-2 stages with half-sizes `m` and `2m` fused into one pass over blocks of `4m`
+fn radix4_stage<F: NttField>(a: &mut [u64], m: usize, twiddles: &[ModInt<F>], cubed: &[ModInt<F>]) {
+    // `w_4m^j`, `w_4m^2j = w_2m^j` and `w_4m^3j`, plus the 4th root of unity `i = w_4`
+    let w1 = &twiddles[2 * m..3 * m];
+    let w2 = &twiddles[m..2 * m];
+    let w3 = &cubed[2 * m..3 * m];
+    let i = twiddles[3];
+    for k in (0..a.len()).step_by(4 * m) {
+        // At `j = 0` all three twiddles are 1
+        let idx = [k, k + m, k + 2 * m, k + 3 * m];
+        let c = idx.map(|x| ModInt::<F>::from_reduced(a[x]));
+        butterfly4(a, idx, c, i);
+        for j in 1..m {
+            let idx = idx.map(|x| x + j);
+            let c = [
+                ModInt::<F>::from_reduced(a[idx[0]]),
+                ModInt::<F>::from_reduced(a[idx[1]]) * w2[j],
+                ModInt::<F>::from_reduced(a[idx[2]]) * w1[j],
+                ModInt::<F>::from_reduced(a[idx[3]]) * w3[j],
+            ];
+            butterfly4(a, idx, c, i);
+        }
+    }
+}
+
+// 4-point butterfly on inputs that already carry their twiddles
+#[inline]
+fn butterfly4<F: NttField>(a: &mut [u64], idx: [usize; 4], c: [ModInt<F>; 4], i: ModInt<F>) {
+    let [c0, c1, c2, c3] = c;
+    let (s01, d01) = (c0 + c1, c0 - c1);
+    let (s23, d23) = (c2 + c3, (c2 - c3) * i);
+    a[idx[0]] = (s01 + s23).value();
+    a[idx[1]] = (d01 + d23).value();
+    a[idx[2]] = (s01 - s23).value();
+    a[idx[3]] = (d01 - d23).value();
+}
+
+/// Reorder `a` so that index `i` moves to `i` with its `log2(len)` bits reversed
+///
+/// Panics if the length is not a power of two.
+pub fn bit_reverse_permute<T>(a: &mut [T]) {
+    assert!(a.len().is_power_of_two(), "length must be a power of two");
+    permute(a, &bit_reverse_table(a.len()));
+}
+
+// `i` with its `bits` low bits reversed, for tables built at compile time
+pub(crate) const fn bit_reverse(i: u64, bits: u32) -> u64 {
+    if bits == 0 {
+        return 0;
+    }
+    i.reverse_bits() >> (64 - bits)
+}
+
+// `rev[i]` is `i` with its `log2(n)` low bits reversed
+pub(crate) fn bit_reverse_table(n: usize) -> Vec<usize> {
+    let mut rev = vec![0; n];
+    for i in 1..n {
+        rev[i] = rev[i >> 1] >> 1 | (if i & 1 == 1 { n >> 1 } else { 0 });
+    }
+    rev
+}
+
+pub(crate) fn permute<T>(a: &mut [T], rev: &[usize]) {
+    for (i, &r) in rev.iter().enumerate() {
+        if i < r {
+            a.swap(i, r);
+        }
+    }
+}
+
+// Twiddles `root^(n/2m * j)` for every stage, laid out by half-size `m`
+pub(crate) fn stage_twiddles<F: NttField>(root: ModInt<F>, n: usize) -> Vec<ModInt<F>> {
+    let mut twiddles = vec![ModInt::zero(); n.max(1)];
+    let mut m = n >> 1;
+    let mut base = root;
+    while m >= 1 {
+        let mut w = ModInt::one();
+        for t in twiddles[m..2 * m].iter_mut() {
+            *t = w;
+            w *= base;
+        }
+        base *= base;
+        m >>= 1;
+    }
+    twiddles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt, MODULUS, PRIMITIVE_ROOT};
+
+    #[test]
+    fn plan_matches_ntt() {
+        let plan: NttPlan = NttPlan::new(8);
+        let og: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut expected = og.clone();
+        ntt(&mut expected, 8, PRIMITIVE_ROOT);
+
+        let mut coefficients = og.clone();
+        plan.forward(&mut coefficients);
+        assert_eq!(coefficients, expected);
+        plan.inverse(&mut coefficients);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn radix4_matches_radix2() {
+        for n in [2, 4, 8, 16, 32, 64] {
+            let radix2 = NttPlan::<F998244353>::new(n);
+            let radix4 = NttPlan::<F998244353>::new(n).with_kernel(Kernel::Radix4);
+            let og: Vec<u64> = (0..n as u64).map(|i| i * i + 7).collect();
+            let mut expected = og.clone();
+            let mut coefficients = og.clone();
+            radix2.forward(&mut expected);
+            radix4.forward(&mut coefficients);
+            assert_eq!(coefficients, expected);
+            radix4.inverse(&mut coefficients);
+            assert_eq!(coefficients, og);
+        }
+    }
+
+    #[test]
+    fn four_step_is_built_on_first_use() {
+        let n = FOUR_STEP_THRESHOLD;
+        let plan: NttPlan = NttPlan::new(n);
+        let mut a = vec![0; n];
+        plan.forward_dif(&mut a);
+        plan.inverse_dit(&mut a);
+        assert!(plan.four_step.get().is_none());
+        plan.forward(&mut a);
+        assert!(plan.four_step.get().is_some());
+        assert!(plan.with_four_step(false).four_step().is_none());
+    }
+
+    #[test]
+    fn unreduced_inputs_transform_like_their_residues() {
+        let n = 16;
+        let reduced: Vec<u64> = (0..n as u64).map(|i| i * 12345 % MODULUS).collect();
+        let unreduced: Vec<u64> = reduced.iter().map(|&x| x + 3 * MODULUS).collect();
+        let mut expected = reduced.clone();
+        ntt(&mut expected, n, PRIMITIVE_ROOT);
+        for kernel in [Kernel::Radix2, Kernel::Radix4] {
+            let plan: NttPlan = NttPlan::new(n).with_kernel(kernel);
+            let mut a = unreduced.clone();
+            plan.forward(&mut a);
+            assert_eq!(a, expected);
+            let mut a = unreduced.clone();
+            plan.inverse_dit(&mut a);
+            plan.forward_dif(&mut a);
+            assert_eq!(a, reduced);
+        }
+    }
+}
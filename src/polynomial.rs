@@ -0,0 +1,350 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{convolve_with, ModInt, NttField, F998244353};
+
+/// Products whose shorter factor has fewer coefficients than this are computed by
+/// schoolbook multiplication
+pub const SCHOOLBOOK_CUTOFF: usize = 32;
+/// Products whose shorter factor has at least this many coefficients go through the
+/// NTT when the padded length fits the field; smaller ones use Karatsuba
+pub const NTT_CUTOFF: usize = 128;
+
+/// Polynomial over `F`, coefficients lowest degree first
+///
+/// Leading zero coefficients are trimmed on construction and after every operation,
+/// so the zero polynomial has no coefficients at all.
+pub struct Polynomial<F: NttField = F998244353> {
+    coefficients: Vec<ModInt<F>>,
+}
+
+impl<F: NttField> Polynomial<F> {
+    /// Polynomial with the given coefficients, each reduced modulo `p`
+    pub fn new(coefficients: Vec<u64>) -> Self {
+        Self::from_modints(coefficients.into_iter().map(ModInt::new).collect())
+    }
+
+    fn from_modints(mut coefficients: Vec<ModInt<F>>) -> Self {
+        while coefficients.last() == Some(&ModInt::zero()) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+
+    pub fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    /// Coefficients, lowest degree first, without leading zeros
+    pub fn coefficients(&self) -> &[ModInt<F>] {
+        &self.coefficients
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Degree, `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Value at `x` by Horner's rule
+    pub fn evaluate(&self, x: u64) -> u64 {
+        let x = ModInt::<F>::new(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(ModInt::zero(), |acc, &c| acc * x + c)
+            .value()
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::from_modints(
+            (1..self.coefficients.len())
+                .map(|i| self.coefficients[i] * ModInt::new(i as u64))
+                .collect(),
+        )
+    }
+
+    /// Antiderivative with zero constant term
+    ///
+    /// Panics if the degree is at least `p - 1`, as `1 / p` does not exist in `F`.
+    pub fn integral(&self) -> Self {
+        let mut coefficients = vec![ModInt::zero()];
+        coefficients.extend(self.coefficients.iter().enumerate().map(|(i, &c)| {
+            let inv = ModInt::<F>::new(i as u64 + 1).inv();
+            c * inv.expect("degree must stay below p - 1")
+        }));
+        Self::from_modints(coefficients)
+    }
+}
+
+impl<F: NttField> Clone for Polynomial<F> {
+    fn clone(&self) -> Self {
+        Self {
+            coefficients: self.coefficients.clone(),
+        }
+    }
+}
+
+impl<F: NttField> PartialEq for Polynomial<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients == other.coefficients
+    }
+}
+
+impl<F: NttField> Eq for Polynomial<F> {}
+
+impl<F: NttField> Hash for Polynomial<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coefficients.hash(state);
+    }
+}
+
+impl<F: NttField> Default for Polynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: NttField> fmt::Debug for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.coefficients, f)
+    }
+}
+
+impl<F: NttField> From<Vec<u64>> for Polynomial<F> {
+    fn from(coefficients: Vec<u64>) -> Self {
+        Self::new(coefficients)
+    }
+}
+
+// Highest degree first, e.g. `3x^2 + x + 5`
+impl<F: NttField> fmt::Display for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            if c.value() == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            if c.value() != 1 || i == 0 {
+                write!(f, "{c}")?;
+            }
+            match i {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{i}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn schoolbook<F: NttField>(a: &[ModInt<F>], b: &[ModInt<F>]) -> Vec<ModInt<F>> {
+    let mut out = vec![ModInt::zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    out
+}
+
+fn add_into<F: NttField>(out: &mut [ModInt<F>], offset: usize, src: &[ModInt<F>]) {
+    for (o, &s) in out[offset..].iter_mut().zip(src) {
+        *o += s;
+    }
+}
+
+fn karatsuba<F: NttField>(a: &[ModInt<F>], b: &[ModInt<F>]) -> Vec<ModInt<F>> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.len() < SCHOOLBOOK_CUTOFF {
+        return schoolbook(long, short);
+    }
+    let mut out = vec![ModInt::zero(); a.len() + b.len() - 1];
+    let h = long.len() / 2;
+    if short.len() <= h {
+        // Too lopsided to split both halves; cut the long factor into short-sized pieces
+        for (k, piece) in long.chunks(short.len()).enumerate() {
+            add_into(&mut out, k * short.len(), &karatsuba(piece, short));
+        }
+        return out;
+    }
+    // `(a0 + a1 x^h)(b0 + b1 x^h)` with the middle term from one product of sums
+    let (a0, a1) = long.split_at(h);
+    let (b0, b1) = short.split_at(h);
+    let sum = |lo: &[ModInt<F>], hi: &[ModInt<F>]| {
+        let mut s = vec![ModInt::zero(); lo.len().max(hi.len())];
+        add_into(&mut s, 0, lo);
+        add_into(&mut s, 0, hi);
+        s
+    };
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut z1 = karatsuba(&sum(a0, a1), &sum(b0, b1));
+    for (i, z) in z1.iter_mut().enumerate() {
+        *z -= z0.get(i).copied().unwrap_or_default() + z2.get(i).copied().unwrap_or_default();
+    }
+    add_into(&mut out, 0, &z0);
+    add_into(&mut out, h, &z1);
+    add_into(&mut out, 2 * h, &z2);
+    out
+}
+
+fn values<F: NttField>(a: &[ModInt<F>]) -> Vec<u64> {
+    a.iter().map(|c| c.value()).collect()
+}
+
+fn ntt_multiply<F: NttField>(a: &[ModInt<F>], b: &[ModInt<F>]) -> Vec<ModInt<F>> {
+    convolve_with::<F>(&values(a), &values(b))
+        .into_iter()
+        .map(ModInt::from_reduced)
+        .collect()
+}
+
+impl<F: NttField> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn add(self, rhs: Self) -> Polynomial<F> {
+        let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coefficients = long.coefficients.clone();
+        add_into(&mut coefficients, 0, &short.coefficients);
+        Polynomial::from_modints(coefficients)
+    }
+}
+
+impl<F: NttField> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        Polynomial::from_modints(self.coefficients.iter().map(|&c| -c).collect())
+    }
+}
+
+impl<F: NttField> Sub for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn sub(self, rhs: Self) -> Polynomial<F> {
+        self + &-rhs
+    }
+}
+
+impl<F: NttField> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: Self) -> Polynomial<F> {
+        let (a, b) = (&self.coefficients, &rhs.coefficients);
+        if a.is_empty() || b.is_empty() {
+            return Polynomial::zero();
+        }
+        let n = (a.len() + b.len() - 1).next_power_of_two();
+        // Fields with a small two-adicity fall back to Karatsuba for long products
+        if a.len().min(b.len()) >= NTT_CUTOFF && n as u64 <= F::max_ntt_size() {
+            Polynomial::from_modints(ntt_multiply(a, b))
+        } else {
+            Polynomial::from_modints(karatsuba(a, b))
+        }
+    }
+}
+
+// Owned operands forward to the by-reference implementations
+macro_rules! forward_owned {
+    ($($Op:ident $op:ident),*) => {
+        $(
+            impl<F: NttField> $Op for Polynomial<F> {
+                type Output = Polynomial<F>;
+
+                fn $op(self, rhs: Self) -> Polynomial<F> {
+                    (&self).$op(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned!(Add add, Sub sub, Mul mul);
+
+impl<F: NttField> Neg for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{convolve, convolve_mod, mul_mod, Kyber, KYBER_Q, MODULUS};
+
+    #[test]
+    fn normalizes_and_formats() {
+        // 3x^2 + 2x + 1
+        let p: Polynomial = Polynomial::new(vec![1, 2, 3, 0, 0]);
+        assert_eq!(p.degree(), Some(2));
+        assert_eq!(p.coefficients().len(), 3);
+        assert_eq!(p.to_string(), "3x^2 + 2x + 1");
+        assert_eq!(
+            Polynomial::<F998244353>::new(vec![0, 1, 0, 1]).to_string(),
+            "x^3 + x"
+        );
+    }
+
+    #[test]
+    fn calculus_and_evaluation() {
+        let p: Polynomial = Polynomial::new(vec![1, 2, 3]);
+        assert_eq!(p.evaluate(2), 17);
+        assert_eq!(p.derivative(), Polynomial::new(vec![2, 6]));
+        assert_eq!(p.integral().derivative(), p);
+    }
+
+    #[test]
+    fn ring_operations() {
+        let p: Polynomial = Polynomial::new(vec![1, 2, 3]);
+        let q: Polynomial = Polynomial::new(vec![MODULUS - 1, 0, MODULUS - 3]);
+        assert_eq!(&p + &q, Polynomial::new(vec![0, 2]));
+        let zero = &p - &p.clone();
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(p.clone() * Polynomial::zero(), Polynomial::zero());
+        assert_eq!(
+            (&p * &q).evaluate(5),
+            mul_mod(p.evaluate(5), q.evaluate(5), MODULUS)
+        );
+    }
+
+    #[test]
+    fn every_multiplication_path_matches_convolve() {
+        // Schoolbook, Karatsuba (balanced and lopsided) and NTT products
+        for (la, lb) in [(10, 7), (50, 60), (50, 300), (200, 300)] {
+            let a: Vec<u64> = (0..la as u64).map(|i| i * 7 + 1).collect();
+            let b: Vec<u64> = (0..lb as u64).map(|i| MODULUS - i * i - 2).collect();
+            let product = Polynomial::<F998244353>::new(a.clone()) * Polynomial::new(b.clone());
+            assert_eq!(product, Polynomial::new(convolve(&a, &b)));
+        }
+    }
+
+    #[test]
+    fn small_two_adicity_stays_on_karatsuba() {
+        // Kyber only has 256-point transforms
+        let a: Vec<u64> = (0..300u64).map(|i| i % KYBER_Q).collect();
+        let b: Vec<u64> = (0..200u64).map(|i| (i * i) % KYBER_Q).collect();
+        let product = Polynomial::<Kyber>::new(a.clone()) * Polynomial::new(b.clone());
+        assert_eq!(product, Polynomial::new(convolve_mod(&a, &b, KYBER_Q)));
+    }
+}
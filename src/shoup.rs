@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+
+use crate::plan::permute;
+use crate::{NttError, NttField, NttPlan, F998244353};
+
+/// `floor(w * 2^64 / p)`, the Shoup companion of a constant multiplier `w < p`
+pub fn shoup_precompute(w: u64, modulus: u64) -> u64 {
+    (((w as u128) << 64) / modulus as u128) as u64
+}
+
+/// `x * w mod p` in `[0, 2p)` given `w_shoup = shoup_precompute(w, p)`
+#[inline]
+pub fn shoup_mul(x: u64, w: u64, w_shoup: u64, modulus: u64) -> u64 {
+    let q = ((x as u128 * w_shoup as u128) >> 64) as u64;
+    x.wrapping_mul(w).wrapping_sub(q.wrapping_mul(modulus))
+}
+
+/// Size-`n` transform plan using Harvey's lazy butterflies with Shoup twiddles
+///
+/// Values stay in `[0, 4p)` between stages and are only fully reduced at the end,
+/// so the field prime must be below `2^62`.
+///
+/// For primes below `2^32` it is no faster than [`NttPlan`]: there a twiddle product
+/// fits in a `u64`, and the `%` by the constant modulus already compiles to a
+/// multiply-and-shift much like [`shoup_mul`]. It pays off for larger primes such as
+/// [`F4179340454199820289`](crate::F4179340454199820289), where `NttPlan` needs a
+/// 128-bit remainder per butterfly; `benches/butterfly.rs` measures both.
+#[derive(Clone, Debug)]
+pub struct ShoupPlan<F: NttField = F998244353> {
+    n: usize,
+    rev: Vec<usize>,
+    twiddles: Vec<u64>,
+    twiddles_shoup: Vec<u64>,
+    inv_twiddles: Vec<u64>,
+    inv_twiddles_shoup: Vec<u64>,
+    n_inv: u64,
+    n_inv_shoup: u64,
+    _field: PhantomData<F>,
+}
+
+impl<F: NttField> ShoupPlan<F> {
+    /// Build a plan for transforms of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, validating the size and modulus
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        if F::MODULUS >= 1 << 62 {
+            return Err(NttError::UnsupportedModulus(F::MODULUS));
+        }
+        let plan = NttPlan::<F>::try_new(n)?;
+        let twiddles: Vec<u64> = plan.twiddles.iter().map(|w| w.value()).collect();
+        let inv_twiddles: Vec<u64> = plan.inv_twiddles.iter().map(|w| w.value()).collect();
+        let shoup = |t: &[u64]| t.iter().map(|&w| shoup_precompute(w, F::MODULUS)).collect();
+        let n_inv = plan.n_inv.value();
+        Ok(Self {
+            n,
+            rev: plan.rev,
+            twiddles_shoup: shoup(&twiddles),
+            twiddles,
+            inv_twiddles_shoup: shoup(&inv_twiddles),
+            inv_twiddles,
+            n_inv,
+            n_inv_shoup: shoup_precompute(n_inv, F::MODULUS),
+            _field: PhantomData,
+        })
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Forward transform of `a` in place; inputs may be anywhere in `[0, 4p)`
+    pub fn forward(&self, a: &mut [u64]) {
+        self.transform(a, &self.twiddles, &self.twiddles_shoup);
+        let p = F::MODULUS;
+        for ai in a[..self.n].iter_mut() {
+            if *ai >= 2 * p {
+                *ai -= 2 * p;
+            }
+            if *ai >= p {
+                *ai -= p;
+            }
+        }
+    }
+
+    /// Inverse transform of `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        self.transform(a, &self.inv_twiddles, &self.inv_twiddles_shoup);
+        let p = F::MODULUS;
+        for ai in a[..self.n].iter_mut() {
+            *ai = shoup_mul(*ai, self.n_inv, self.n_inv_shoup, p);
+            if *ai >= p {
+                *ai -= p;
+            }
+        }
+    }
+
+    fn transform(&self, a: &mut [u64], twiddles: &[u64], twiddles_shoup: &[u64]) {
+        let n = self.n;
+        let two_p = 2 * F::MODULUS;
+        let a = &mut a[..n];
+        permute(a, &self.rev);
+        let mut m = 1;
+        while m < n {
+            let w = &twiddles[m..2 * m];
+            let w_shoup = &twiddles_shoup[m..2 * m];
+            for block in a.chunks_exact_mut(2 * m) {
+                let (lo, hi) = block.split_at_mut(m);
+                for (((x, y), &w), &w_shoup) in lo.iter_mut().zip(hi).zip(w).zip(w_shoup) {
+                    // `u - 2p` wraps to a huge value when `u < 2p`, so the minimum brings
+                    // `u` into `[0, 2p)` without a branch the predictor can't learn
+                    let u = (*x).min(x.wrapping_sub(two_p));
+                    let t = shoup_mul(*y, w, w_shoup, F::MODULUS);
+                    *x = u + t;
+                    *y = u + two_p - t;
+                }
+            }
+            m <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt, Goldilocks, PRIMITIVE_ROOT};
+
+    #[test]
+    fn shoup_plan_matches_ntt() {
+        let og: Vec<u64> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut expected = og.clone();
+        ntt(&mut expected, 8, PRIMITIVE_ROOT);
+
+        let plan: ShoupPlan = ShoupPlan::new(8);
+        let mut coefficients = og.clone();
+        plan.forward(&mut coefficients);
+        assert_eq!(coefficients, expected);
+        plan.inverse(&mut coefficients);
+        assert_eq!(coefficients, og);
+    }
+
+    #[test]
+    fn rejects_primes_above_2_62() {
+        assert!(ShoupPlan::<Goldilocks>::try_new(8).is_err());
+    }
+}
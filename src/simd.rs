@@ -0,0 +1,281 @@
+use std::marker::PhantomData;
+
+use crate::plan::permute;
+use crate::{ModInt, NttError, NttField, NttPlan, F998244353};
+
+// Montgomery multiplication with `R = 2^32`: `x * y * 2^-32 mod p` in `[0, p)`, for `p < 2^31`.
+// The vector kernel computes exactly the same thing lane by lane.
+#[inline]
+fn mont32_mul(x: u64, y: u64, p: u64, p_neg_inv: u64) -> u64 {
+    let t = x * y;
+    let m = (t as u32).wrapping_mul(p_neg_inv as u32) as u64;
+    let r = (t + m * p) >> 32;
+    if r >= p {
+        r - p
+    } else {
+        r
+    }
+}
+
+/// Size-`n` transform plan with vectorized butterflies for primes below `2^31`
+///
+/// On x86_64 CPUs with AVX2 (detected at runtime) eight butterflies run per
+/// instruction using 32-bit Montgomery multiplication against pre-scaled twiddles:
+/// the input is packed into `u32` lanes in place for the vector stages and widened
+/// back afterwards. Elsewhere, including AVX-512 and NEON targets for now, the plan
+/// falls back to the scalar [`NttPlan`]. Both paths produce identical, fully reduced
+/// output.
+#[derive(Clone, Debug)]
+pub struct SimdPlan<F: NttField = F998244353> {
+    plan: NttPlan<F>,
+    // Twiddles times `2^32 mod p`, so a Montgomery product with a plain value stays plain
+    twiddles: Vec<u32>,
+    inv_twiddles: Vec<u32>,
+    n_inv: u64,
+    p_neg_inv: u64,
+    accelerated: bool,
+    _field: PhantomData<F>,
+}
+
+impl<F: NttField> SimdPlan<F> {
+    /// Build a plan for transforms of length `n` (a power of two)
+    pub fn new(n: usize) -> Self {
+        match Self::try_new(n) {
+            Ok(plan) => plan,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Build a plan for transforms of length `n`, validating the size and modulus
+    pub fn try_new(n: usize) -> Result<Self, NttError> {
+        if F::MODULUS >= 1 << 31 {
+            return Err(NttError::UnsupportedModulus(F::MODULUS));
+        }
+        let plan = NttPlan::<F>::try_new(n)?;
+        let r = ModInt::<F>::new(1 << 32);
+        let scale = |t: &[ModInt<F>]| t.iter().map(|&w| (w * r).value() as u32).collect();
+        // `-p^-1 mod 2^32` by Newton iteration
+        let mut inv = 1u32;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub((F::MODULUS as u32).wrapping_mul(inv)));
+        }
+        Ok(Self {
+            twiddles: scale(&plan.twiddles),
+            inv_twiddles: scale(&plan.inv_twiddles),
+            n_inv: (plan.n_inv * r).value(),
+            p_neg_inv: inv.wrapping_neg() as u64,
+            accelerated: detect_avx2(),
+            plan,
+            _field: PhantomData,
+        })
+    }
+
+    /// Use the scalar fallback even if the CPU supports the vector kernel
+    pub fn scalar(mut self) -> Self {
+        self.accelerated = false;
+        self
+    }
+
+    /// Whether transforms run on the vector kernel
+    pub fn is_accelerated(&self) -> bool {
+        self.accelerated
+    }
+
+    /// Transform length
+    pub fn len(&self) -> usize {
+        self.plan.len()
+    }
+
+    /// Whether the plan is for the empty transform
+    pub fn is_empty(&self) -> bool {
+        self.plan.is_empty()
+    }
+
+    /// Forward transform of `a` in place
+    pub fn forward(&self, a: &mut [u64]) {
+        if !self.accelerated {
+            return self.plan.forward(a);
+        }
+        let a = &mut a[..self.len()];
+        permute(a, &self.plan.rev);
+        self.stages(a, &self.twiddles);
+    }
+
+    /// Inverse transform of `a` in place, including the `n^-1` scaling
+    pub fn inverse(&self, a: &mut [u64]) {
+        if !self.accelerated {
+            return self.plan.inverse(a);
+        }
+        let a = &mut a[..self.len()];
+        permute(a, &self.plan.rev);
+        self.stages(a, &self.inv_twiddles);
+        for ai in a.iter_mut() {
+            *ai = mont32_mul(*ai, self.n_inv, F::MODULUS, self.p_neg_inv);
+        }
+    }
+
+    fn stages(&self, a: &mut [u64], twiddles: &[u32]) {
+        let (p, p_neg_inv) = (F::MODULUS, self.p_neg_inv);
+        for ai in a.iter_mut() {
+            *ai %= p;
+        }
+        // Stages narrower than a vector stay scalar
+        let mut m = 1;
+        while m < a.len() && m < 8 {
+            let w = &twiddles[m..2 * m];
+            for k in (0..a.len()).step_by(2 * m) {
+                for j in 0..m {
+                    let u = a[k + j];
+                    let t = mont32_mul(a[k + j + m], w[j] as u64, p, p_neg_inv);
+                    a[k + j] = if u + t >= p { u + t - p } else { u + t };
+                    a[k + j + m] = if u >= t { u - t } else { u + p - t };
+                }
+            }
+            m <<= 1;
+        }
+        #[cfg(target_arch = "x86_64")]
+        if m < a.len() {
+            // SAFETY: `accelerated` is only set when AVX2 was detected at runtime
+            unsafe { avx2::stages(a, twiddles, m, p, p_neg_inv) };
+        }
+    }
+}
+
+fn detect_avx2() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    // Eight 32-bit lanes, each holding a value below `2p < 2^32`
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn reduce_once(x: __m256i, p: __m256i) -> __m256i {
+        // `x - p` wraps to a larger value exactly when `x < p`
+        _mm256_min_epu32(x, _mm256_sub_epi32(x, p))
+    }
+
+    // `mul_epu32` only multiplies the even lanes, so the odd ones are shifted down,
+    // reduced alongside, and blended back in
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn mont32_mul(x: __m256i, y: __m256i, p: __m256i, p_neg_inv: __m256i) -> __m256i {
+        let reduce = |t: __m256i| {
+            let m = _mm256_mul_epu32(t, p_neg_inv);
+            _mm256_srli_epi64::<32>(_mm256_add_epi64(t, _mm256_mul_epu32(m, p)))
+        };
+        let even = reduce(_mm256_mul_epu32(x, y));
+        let odd = reduce(_mm256_mul_epu32(
+            _mm256_srli_epi64::<32>(x),
+            _mm256_srli_epi64::<32>(y),
+        ));
+        let r = _mm256_blend_epi32::<0b1010_1010>(even, _mm256_slli_epi64::<32>(odd));
+        reduce_once(r, p)
+    }
+
+    // Radix-2 stages from half-size `m` (at least 8) up to the full length
+    //
+    // The values, all below `p < 2^31`, are narrowed to `u32` in place at the front of
+    // `a` so that a vector holds eight of them, and widened again at the end.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn stages(
+        a: &mut [u64],
+        twiddles: &[u32],
+        mut m: usize,
+        p: u64,
+        p_neg_inv: u64,
+    ) {
+        let n = a.len();
+        let wide = a.as_mut_ptr();
+        let narrow = wide as *mut u32;
+        // SAFETY: `narrow[i]` overlaps only `a[i / 2]`, which has been read by then,
+        // going up; going down, `a[i]` overlaps `narrow[2i..2i + 2]`, already read
+        unsafe {
+            for i in 0..n {
+                narrow.add(i).write(wide.add(i).read() as u32);
+            }
+        }
+
+        let pv = _mm256_set1_epi32(p as i32);
+        let qv = _mm256_set1_epi32(p_neg_inv as i32);
+        while m < n {
+            let w = twiddles[m..2 * m].as_ptr();
+            for k in (0..n).step_by(2 * m) {
+                for j in (0..m).step_by(8) {
+                    // SAFETY: `k + j + m + 8 <= n` and `j + 8 <= m`, as `m` is a
+                    // multiple of 8 and `n` of `2m`
+                    unsafe {
+                        let lo = narrow.add(k + j) as *mut __m256i;
+                        let hi = narrow.add(k + j + m) as *mut __m256i;
+                        let u = _mm256_loadu_si256(lo);
+                        let wj = _mm256_loadu_si256(w.add(j) as *const __m256i);
+                        let t = mont32_mul(_mm256_loadu_si256(hi), wj, pv, qv);
+                        let sum = reduce_once(_mm256_add_epi32(u, t), pv);
+                        let diff = reduce_once(_mm256_add_epi32(_mm256_sub_epi32(u, t), pv), pv);
+                        _mm256_storeu_si256(lo, sum);
+                        _mm256_storeu_si256(hi, diff);
+                    }
+                }
+            }
+            m <<= 1;
+        }
+
+        // SAFETY: as for the narrowing above
+        unsafe {
+            for i in (0..n).rev() {
+                wide.add(i).write(narrow.add(i).read() as u64);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dilithium, Goldilocks, F167772161, F469762049, F754974721};
+
+    fn matches_scalar<F: NttField>() {
+        for log_n in 0..=12 {
+            let n = 1 << log_n;
+            let og: Vec<u64> = (0..n as u64)
+                .map(|i| (i * 7919 + 13) % F::MODULUS)
+                .collect();
+            let plan = NttPlan::<F>::new(n);
+            let simd = SimdPlan::<F>::new(n);
+            let scalar = SimdPlan::<F>::new(n).scalar();
+
+            let mut expected = og.clone();
+            plan.forward(&mut expected);
+            for p in [&simd, &scalar] {
+                let mut coefficients = og.clone();
+                p.forward(&mut coefficients);
+                assert_eq!(coefficients, expected);
+                p.inverse(&mut coefficients);
+                assert_eq!(coefficients, og);
+            }
+        }
+    }
+
+    #[test]
+    fn simd_matches_scalar() {
+        matches_scalar::<F998244353>();
+        matches_scalar::<F469762049>();
+        matches_scalar::<F167772161>();
+        matches_scalar::<F754974721>();
+        matches_scalar::<Dilithium>();
+    }
+
+    #[test]
+    fn rejects_primes_above_2_31() {
+        assert!(SimdPlan::<Goldilocks>::try_new(8).is_err());
+    }
+}
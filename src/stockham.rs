@@ -0,0 +1,152 @@
+use crate::{check_size, ModInt, NttError, NttField, F998244353};
+
+fn check_len(expected: usize, actual: usize) -> Result<(), NttError> {
+    if actual < expected {
+        return Err(NttError::LengthMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+// One Stockham pass per stage, ping-ponging between `dst` and `scratch` so that the
+// last pass lands in `dst`; each pass writes its outputs already sorted, so no bit
+// reversal is needed
+fn stockham<F: NttField>(src: &[u64], dst: &mut [u64], scratch: &mut [u64], root: ModInt<F>) {
+    let n = src.len();
+    if n == 1 {
+        dst[0] = ModInt::<F>::new(src[0]).value();
+        return;
+    }
+    let stages = n.trailing_zeros();
+    let mut l = n >> 1;
+    let mut m = 1;
+    for stage in 0..stages {
+        let (x, y): (&[u64], &mut [u64]) = match (stage, (stages - 1 - stage) % 2) {
+            (0, 0) => (src, &mut dst[..n]),
+            (0, _) => (src, &mut scratch[..n]),
+            (_, 0) => (&scratch[..n], &mut dst[..n]),
+            _ => (&dst[..n], &mut scratch[..n]),
+        };
+        // Only the first pass reads `src`, which may hold unreduced values
+        let load = |v: u64| {
+            if stage == 0 {
+                ModInt::<F>::new(v)
+            } else {
+                ModInt::<F>::from_reduced(v)
+            }
+        };
+        // `w_2l = root^(n / 2l) = root^m`
+        let base = root.pow(m as u64);
+        let mut w = ModInt::<F>::one();
+        for j in 0..l {
+            for k in 0..m {
+                let c0 = load(x[k + j * m]);
+                let c1 = load(x[k + j * m + l * m]);
+                y[k + 2 * j * m] = (c0 + c1).value();
+                y[k + 2 * j * m + m] = ((c0 - c1) * w).value();
+            }
+            w *= base;
+        }
+        l >>= 1;
+        m <<= 1;
+    }
+}
+
+/// Out-of-place Stockham NTT over `F` from `src` into `dst`, natural order in and out
+///
+/// `src` is left untouched; `dst` must be as long as `src` and `scratch` at least as
+/// long. The result equals [`ntt_with`](crate::ntt_with) with the field generator.
+pub fn ntt_into_with<F: NttField>(
+    src: &[u64],
+    dst: &mut [u64],
+    scratch: &mut [u64],
+) -> Result<(), NttError> {
+    let n = src.len();
+    check_size::<F>(n)?;
+    check_len(n, dst.len())?;
+    check_len(n, scratch.len())?;
+    let root = ModInt::new(F::root_of_unity(n as u64).expect("size was checked"));
+    stockham::<F>(src, dst, scratch, root);
+    Ok(())
+}
+
+/// Out-of-place inverse of [`ntt_into_with`], including the `n^-1` scaling
+pub fn intt_into_with<F: NttField>(
+    src: &[u64],
+    dst: &mut [u64],
+    scratch: &mut [u64],
+) -> Result<(), NttError> {
+    let n = src.len();
+    check_size::<F>(n)?;
+    check_len(n, dst.len())?;
+    check_len(n, scratch.len())?;
+    let root = ModInt::<F>::new(F::root_of_unity(n as u64).expect("size was checked"));
+    stockham::<F>(
+        src,
+        dst,
+        scratch,
+        root.inv().expect("roots of unity are invertible"),
+    );
+    let n_inv = ModInt::<F>::new(n as u64).inv().expect("n is below p");
+    for x in dst[..n].iter_mut() {
+        *x = (ModInt::<F>::from_reduced(*x) * n_inv).value();
+    }
+    Ok(())
+}
+
+/// Out-of-place NTT modulo `MODULUS`; see [`ntt_into_with`]
+pub fn ntt_into(src: &[u64], dst: &mut [u64], scratch: &mut [u64]) -> Result<(), NttError> {
+    ntt_into_with::<F998244353>(src, dst, scratch)
+}
+
+/// Out-of-place inverse NTT modulo `MODULUS`; see [`intt_into_with`]
+pub fn intt_into(src: &[u64], dst: &mut [u64], scratch: &mut [u64]) -> Result<(), NttError> {
+    intt_into_with::<F998244353>(src, dst, scratch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ntt, Goldilocks, MODULUS, PRIMITIVE_ROOT};
+
+    #[test]
+    fn stockham_matches_ntt() {
+        for log_n in 0..=10 {
+            let n = 1 << log_n;
+            let src: Vec<u64> = (0..n as u64).map(|i| i * 7919 % MODULUS).collect();
+            let mut expected = src.clone();
+            ntt(&mut expected, n, PRIMITIVE_ROOT);
+
+            let mut dst = vec![0; n];
+            let mut scratch = vec![0; n];
+            assert!(ntt_into(&src, &mut dst, &mut scratch).is_ok());
+            assert_eq!(dst, expected);
+            let mut back = vec![0; n];
+            assert!(intt_into(&dst, &mut back, &mut scratch).is_ok());
+            assert_eq!(back, src);
+        }
+    }
+
+    #[test]
+    fn unreduced_inputs_transform_like_their_residues() {
+        let n = 16;
+        let reduced: Vec<u64> = (0..n as u64).map(|i| i * 12345 % MODULUS).collect();
+        let mut expected = reduced.clone();
+        ntt(&mut expected, n, PRIMITIVE_ROOT);
+        let unreduced: Vec<u64> = reduced.iter().map(|&x| x + 3 * MODULUS).collect();
+        let mut dst = vec![0; n];
+        assert!(ntt_into(&unreduced, &mut dst, &mut vec![0; n]).is_ok());
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn rejects_short_scratch() {
+        let mut dst = vec![0; 8];
+        assert_eq!(
+            ntt_into_with::<Goldilocks>(&[1, 2, 3, 4, 5, 6, 7, 8], &mut dst, &mut [0; 4]),
+            Err(NttError::LengthMismatch {
+                expected: 8,
+                actual: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,37 @@
+// Reference implementations the unit tests compare the transforms against
+
+use crate::{ModInt, NttField};
+
+// `X_k = sum_j a_j w^(jk)` straight from the definition
+pub(crate) fn naive_dft<F: NttField>(a: &[u64]) -> Vec<u64> {
+    let n = a.len();
+    let w = ModInt::<F>::new(F::root_of_unity(n as u64).unwrap());
+    (0..n)
+        .map(|k| {
+            a.iter()
+                .enumerate()
+                .fold(ModInt::<F>::zero(), |acc, (j, &x)| {
+                    acc + ModInt::new(x) * w.pow((j * k) as u64)
+                })
+                .value()
+        })
+        .collect()
+}
+
+// `a * b mod X^n + 1` by the quadratic schoolbook product
+pub(crate) fn negacyclic_schoolbook<F: NttField>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let n = a.len();
+    let mut res = vec![ModInt::<F>::zero(); n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = ModInt::<F>::new(x) * ModInt::new(y);
+            // X^n = -1
+            if i + j < n {
+                res[i + j] += product;
+            } else {
+                res[i + j - n] -= product;
+            }
+        }
+    }
+    res.into_iter().map(|x| x.value()).collect()
+}